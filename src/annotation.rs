@@ -17,12 +17,35 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::{ffi::CString, os::raw::c_ulong};
+
 use crate::{
+    c_api::utf16::{read_utf16, utf16_to_string},
     error::{PdfiumError, PdfiumResult},
-    lib,
-    pdfium_types::{AnnotationHandle, Handle, FPDF_ANNOTATION},
+    lib, pdfium_constants,
+    pdfium_types::{
+        AnnotationHandle, Handle, FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE, FS_POINTF,
+        FS_QUADPOINTSF,
+    },
+    PdfiumColor, PdfiumForm, PdfiumPoint, PdfiumQuad, PdfiumRect,
 };
 
+/// Summary of an annotation, as returned by [`crate::PdfiumDocument::all_annotations`]
+#[derive(Debug, Clone)]
+pub struct PdfiumAnnotationRef {
+    /// Zero-based index of the page holding the annotation
+    pub page_index: i32,
+
+    /// Subtype of the annotation, one of the `FPDF_ANNOT_*` constants
+    pub subtype: FPDF_ANNOTATION_SUBTYPE,
+
+    /// Annotation rectangle in page coordinates
+    pub rect: PdfiumRect,
+
+    /// Text of the annotation, if any
+    pub contents: Option<String>,
+}
+
 /// # Rust interface to FPDF_ANNOTATION
 #[derive(Debug, Clone)]
 pub struct PdfiumAnnotation {
@@ -39,6 +62,183 @@ impl PdfiumAnnotation {
             })
         }
     }
+
+    /// Returns the subtype of this [`PdfiumAnnotation`] as one of the `FPDF_ANNOT_*`
+    /// constants, or `FPDF_ANNOT_UNKNOWN` on failure.
+    pub fn subtype(&self) -> FPDF_ANNOTATION_SUBTYPE {
+        lib().FPDFAnnot_GetSubtype(self)
+    }
+
+    /// Returns the text (`/Contents`) of this [`PdfiumAnnotation`], or `None` if it has none.
+    pub fn contents(&self) -> Option<String> {
+        let key = CString::new("Contents").unwrap();
+        let lib = lib();
+        if lib.FPDFAnnot_HasKey(self, &key) == 0 {
+            return None;
+        }
+        read_utf16(|buffer, buflen| lib.FPDFAnnot_GetStringValue(self, &key, buffer, buflen))
+    }
+
+    /// Returns the annotation rectangle of this [`PdfiumAnnotation`] in page coordinates.
+    pub fn rect(&self) -> PdfiumResult<PdfiumRect> {
+        let mut rect = (&PdfiumRect::zero()).into();
+        lib().FPDFAnnot_GetRect(self, &mut rect)?;
+        Ok(rect.into())
+    }
+
+    /// Returns the number of sets of quadpoints of this [`PdfiumAnnotation`].
+    ///
+    /// Returns 0 on failure or if this annotation has no attachment points.
+    pub fn attachment_points_count(&self) -> usize {
+        lib().FPDFAnnot_CountAttachmentPoints(self)
+    }
+
+    /// Returns the attachment points (i.e. quadpoints) of this [`PdfiumAnnotation`] at
+    /// `quad_index`.
+    pub fn attachment_points(&self, quad_index: usize) -> PdfiumResult<PdfiumQuad> {
+        let zero = PdfiumPoint::zero();
+        let mut quad_points: FS_QUADPOINTSF = (&PdfiumQuad::new(zero, zero, zero, zero)).into();
+        lib().FPDFAnnot_GetAttachmentPoints(self, quad_index, &mut quad_points)?;
+        Ok(quad_points.into())
+    }
+
+    /// Replaces the attachment points (i.e. quadpoints) of this [`PdfiumAnnotation`] at
+    /// `quad_index`.
+    ///
+    /// If the annotation's appearance stream is defined and this annotation is of a type
+    /// with quadpoints, then the bounding box is updated too if the new quadpoints define
+    /// a bigger one.
+    pub fn set_attachment_points(&self, quad_index: usize, quad: &PdfiumQuad) -> PdfiumResult<()> {
+        lib().FPDFAnnot_SetAttachmentPoints(self, quad_index, &quad.into())
+    }
+
+    /// Appends to the list of attachment points (i.e. quadpoints) of this [`PdfiumAnnotation`].
+    ///
+    /// If the annotation's appearance stream is defined and this annotation is of a type
+    /// with quadpoints, then the bounding box is updated too if the new quadpoints define
+    /// a bigger one.
+    pub fn append_attachment_points(&self, quad: &PdfiumQuad) -> PdfiumResult<()> {
+        lib().FPDFAnnot_AppendAttachmentPoints(self, &quad.into())
+    }
+
+    /// Returns the start and end point of this line [`PdfiumAnnotation`].
+    pub fn line(&self) -> PdfiumResult<(PdfiumPoint, PdfiumPoint)> {
+        let mut start: FS_POINTF = (&PdfiumPoint::zero()).into();
+        let mut end: FS_POINTF = (&PdfiumPoint::zero()).into();
+        lib().FPDFAnnot_GetLine(self, &mut start, &mut end)?;
+        Ok((start.into(), end.into()))
+    }
+
+    /// Returns the vertices of this polygon or polyline [`PdfiumAnnotation`].
+    ///
+    /// Returns an empty `Vec` if this annotation has no vertices.
+    pub fn vertices(&self) -> Vec<PdfiumPoint> {
+        let lib = lib();
+        let mut buffer: Vec<FS_POINTF> = vec![(&PdfiumPoint::zero()).into()];
+        let count = lib.FPDFAnnot_GetVertices(self, &mut buffer[0], 0) as usize;
+        if count == 0 {
+            return Vec::new();
+        }
+        buffer.resize(count, buffer[0]);
+        lib.FPDFAnnot_GetVertices(self, &mut buffer[0], count as c_ulong);
+        buffer.into_iter().map(PdfiumPoint::from).collect()
+    }
+
+    /// Returns the number of paths in the ink list of this ink [`PdfiumAnnotation`].
+    pub fn ink_list_count(&self) -> usize {
+        lib().FPDFAnnot_GetInkListCount(self) as usize
+    }
+
+    /// Returns the points of the path at `path_index` in the ink list of this ink
+    /// [`PdfiumAnnotation`].
+    pub fn ink_list_path(&self, path_index: usize) -> PdfiumResult<Vec<PdfiumPoint>> {
+        let lib = lib();
+        let path_index = path_index as c_ulong;
+        let mut buffer: Vec<FS_POINTF> = vec![(&PdfiumPoint::zero()).into()];
+        let count = lib.FPDFAnnot_GetInkListPath(self, path_index, &mut buffer[0], 0) as usize;
+        if count == 0 {
+            return Err(PdfiumError::NotFound);
+        }
+        buffer.resize(count, buffer[0]);
+        lib.FPDFAnnot_GetInkListPath(self, path_index, &mut buffer[0], count as c_ulong);
+        Ok(buffer.into_iter().map(PdfiumPoint::from).collect())
+    }
+
+    /// Adds a stroke with the given `points` to the ink list of this ink [`PdfiumAnnotation`].
+    ///
+    /// Returns the index of the added stroke in the ink list.
+    pub fn add_ink_stroke(&self, points: &[PdfiumPoint]) -> PdfiumResult<i32> {
+        let points: Vec<FS_POINTF> = points.iter().map(FS_POINTF::from).collect();
+        let Some(first) = points.first() else {
+            return Err(PdfiumError::InvokationFailed);
+        };
+        match lib().FPDFAnnot_AddInkStroke(self, first, points.len()) {
+            -1 => Err(PdfiumError::InvokationFailed),
+            index => Ok(index),
+        }
+    }
+
+    /// Returns the font size of this [`PdfiumAnnotation`] with variable text, such as a
+    /// text field widget.
+    ///
+    /// A font size of `0.0` means the font is auto-sized: its size is computed from the
+    /// height of the annotation rectangle.
+    pub fn font_size(&self, form: &PdfiumForm) -> PdfiumResult<f32> {
+        let mut size = 0.0;
+        lib().FPDFAnnot_GetFontSize(form, self, &mut size)?;
+        Ok(size)
+    }
+
+    /// Returns the font color of this [`PdfiumAnnotation`] with variable text.
+    ///
+    /// Returns an error if the annotation does not specify a font color.
+    pub fn font_color(&self, form: &PdfiumForm) -> PdfiumResult<PdfiumColor> {
+        let (mut r, mut g, mut b) = (0, 0, 0);
+        lib().FPDFAnnot_GetFontColor(form, self, &mut r, &mut g, &mut b)?;
+        Ok(PdfiumColor::new(r as u8, g as u8, b as u8, 255))
+    }
+
+    /// Returns the JavaScript run on `event` of this form field [`PdfiumAnnotation`], or
+    /// `None` if there is none.
+    ///
+    /// The script is only read, never executed, so it can safely be inspected by security
+    /// tools or form logic analyzers.
+    pub fn additional_action_js(
+        &self,
+        form: &PdfiumForm,
+        event: PdfiumAnnotAction,
+    ) -> PdfiumResult<Option<String>> {
+        let lib = lib();
+        let mut buffer: Vec<u16> = Vec::new();
+        let len = lib.FPDFAnnot_GetFormAdditionalActionJavaScript(
+            form,
+            self,
+            event as i32,
+            &mut buffer,
+            0,
+        );
+        match len {
+            0 => return Err(PdfiumError::InvokationFailed),
+            1..=2 => return Ok(None),
+            _ => {}
+        }
+        buffer.resize(len as usize / 2, 0);
+        lib.FPDFAnnot_GetFormAdditionalActionJavaScript(form, self, event as i32, &mut buffer, len);
+        Ok(Some(utf16_to_string(&buffer)))
+    }
+
+    /// Sets the font color of this [`PdfiumAnnotation`] with variable text.
+    ///
+    /// The alpha component of `color` is ignored.
+    pub fn set_font_color(&self, form: &PdfiumForm, color: PdfiumColor) -> PdfiumResult<()> {
+        lib().FPDFAnnot_SetFontColor(
+            form,
+            self,
+            color.red() as u32,
+            color.green() as u32,
+            color.blue() as u32,
+        )
+    }
 }
 
 impl From<&PdfiumAnnotation> for FPDF_ANNOTATION {
@@ -50,3 +250,97 @@ impl From<&PdfiumAnnotation> for FPDF_ANNOTATION {
 fn close_annotation(annotation: FPDF_ANNOTATION) {
     lib().FPDFPage_CloseAnnot(annotation);
 }
+
+/// Form field events with JavaScript actions, see [`PdfiumAnnotation::additional_action_js`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(i32)]
+pub enum PdfiumAnnotAction {
+    /// Run when the user types in the field, or changes the selection of a list box
+    KeyStroke = pdfium_constants::FPDF_ANNOT_AACTION_KEY_STROKE,
+    /// Run before the field is formatted for display
+    Format = pdfium_constants::FPDF_ANNOT_AACTION_FORMAT,
+    /// Run when the value of the field changes, to check whether it is valid
+    Validate = pdfium_constants::FPDF_ANNOT_AACTION_VALIDATE,
+    /// Run to recalculate the value of the field when another field changes
+    Calculate = pdfium_constants::FPDF_ANNOT_AACTION_CALCULATE,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{pdfium_constants::FPDF_ANNOT_INK, *};
+
+    #[test]
+    fn test_ink_stroke() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 200.0, 200.0).unwrap();
+        let annotation = lib().FPDFPage_CreateAnnot(&page, FPDF_ANNOT_INK).unwrap();
+        assert_eq!(annotation.ink_list_count(), 0);
+
+        let stroke = [
+            PdfiumPoint::new(10.0, 10.0),
+            PdfiumPoint::new(50.0, 80.0),
+            PdfiumPoint::new(90.0, 20.0),
+        ];
+        assert_eq!(annotation.add_ink_stroke(&stroke).unwrap(), 0);
+        assert_eq!(annotation.ink_list_count(), 1);
+        assert_eq!(annotation.ink_list_path(0).unwrap(), stroke);
+        assert!(annotation.ink_list_path(1).is_err());
+
+        assert!(annotation.add_ink_stroke(&[]).is_err());
+    }
+
+    #[test]
+    fn test_font_size_and_color() {
+        let document = PdfiumDocument::new_from_path("resources/form.pdf", None).unwrap();
+        let form = PdfiumForm::new(&document).unwrap();
+        let page = document.page(0).unwrap();
+        let fields = form.fields(&page);
+
+        // Text field "name" uses `/Helv 12 Tf 0 g`
+        let name = &fields[0].annotation;
+        assert_eq!(name.font_size(&form).unwrap(), 12.0);
+        assert_eq!(name.font_color(&form).unwrap(), PdfiumColor::BLACK);
+
+        let color = PdfiumColor::new(200, 30, 10, 255);
+        name.set_font_color(&form, color).unwrap();
+        assert_eq!(name.font_color(&form).unwrap(), color);
+
+        // Check box "subscribe" uses an auto-sized font (`/ZaDb 0 Tf`)
+        assert_eq!(fields[1].annotation.font_size(&form).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_additional_action_js() {
+        let document = PdfiumDocument::new_from_path("resources/form_calculate.pdf", None).unwrap();
+        let form = PdfiumForm::new(&document).unwrap();
+        let page = document.page(0).unwrap();
+        let fields = form.fields(&page);
+
+        // Field "total" sums fields "a" and "b"
+        let total = &fields[2].annotation;
+        assert_eq!(
+            total
+                .additional_action_js(&form, PdfiumAnnotAction::Calculate)
+                .unwrap()
+                .as_deref(),
+            Some(r#"event.value = this.getField("a").value + this.getField("b").value;"#)
+        );
+        assert!(total
+            .additional_action_js(&form, PdfiumAnnotAction::Format)
+            .unwrap()
+            .is_some_and(|js| js.starts_with("AFNumber_Format")));
+        assert_eq!(
+            total
+                .additional_action_js(&form, PdfiumAnnotAction::KeyStroke)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            fields[0]
+                .annotation
+                .additional_action_js(&form, PdfiumAnnotAction::Calculate)
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -17,16 +17,49 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::{
+    cell::Cell,
+    ffi::CString,
+    fmt::Debug,
+    io::{Read, Seek},
+    rc::Rc,
+};
+
 use crate::{
+    document::reader::PdfiumReader,
     error::{PdfiumError, PdfiumResult},
     lib,
-    pdfium_types::{AvailabilityHandle, Handle, FPDF_AVAIL},
+    pdfium_types::{
+        AvailabilityHandle, Handle, FPDF_AVAIL, FPDF_BOOL, FX_DOWNLOADHINTS, FX_FILEAVAIL,
+    },
+    try_lib, PdfiumDocument,
 };
 
+/// Data not yet available.
+const PDF_DATA_NOTAVAIL: i32 = 0;
+/// Data available.
+const PDF_DATA_AVAIL: i32 = 1;
+
 /// # Rust interface to FPDF_AVAIL
-#[derive(Debug, Clone)]
+///
+/// Document availability provider for progressive loading. Only the first `available`
+/// bytes of the reader are considered downloaded. For linearized PDFs this allows
+/// loading and rendering the first page before the rest of the file has arrived.
+#[derive(Clone)]
 pub struct PdfiumAvailability {
     handle: AvailabilityHandle,
+    file_avail: Option<Rc<PdfiumFileAvail>>,
+    #[allow(clippy::redundant_allocation)]
+    reader: Option<Rc<Box<PdfiumReader>>>,
+}
+
+impl Debug for PdfiumAvailability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PdfiumAvailability")
+            .field("handle", &self.handle)
+            .field("available", &self.available())
+            .finish()
+    }
 }
 
 impl PdfiumAvailability {
@@ -36,9 +69,79 @@ impl PdfiumAvailability {
         } else {
             Ok(Self {
                 handle: Handle::new(handle, Some(close_availability)),
+                file_avail: None,
+                reader: None,
             })
         }
     }
+
+    /// Creates a new [`PdfiumAvailability`] for `reader`, of which the first `available`
+    /// bytes can be read.
+    ///
+    /// The reader must report the full length of the document when seeking to the end.
+    /// Use [`PdfiumAvailability::set_available`] as more data arrives.
+    pub fn new<R: Read + Seek + 'static>(reader: R, available: u64) -> PdfiumResult<Self> {
+        let lib = try_lib()?;
+        let mut reader = PdfiumReader::new(reader);
+        let mut file_avail = Rc::new(PdfiumFileAvail {
+            file_avail: FX_FILEAVAIL {
+                version: 1,
+                IsDataAvail: Some(is_data_avail_callback),
+            },
+            available: Cell::new(available),
+        });
+        let fx_file_avail = &mut Rc::get_mut(&mut file_avail).unwrap().file_avail;
+        let mut availability = lib.FPDFAvail_Create(fx_file_avail, &mut reader)?;
+        availability.file_avail = Some(file_avail);
+        availability.reader = Some(Rc::new(reader));
+        Ok(availability)
+    }
+
+    /// Returns the number of bytes that are available.
+    pub fn available(&self) -> u64 {
+        self.file_avail
+            .as_ref()
+            .map_or(0, |file_avail| file_avail.available.get())
+    }
+
+    /// Updates the number of bytes that are available, e.g. while downloading.
+    pub fn set_available(&self, available: u64) {
+        if let Some(file_avail) = &self.file_avail {
+            file_avail.available.set(available);
+        }
+    }
+
+    /// Returns `true` if the document is linearized ("fast web view").
+    ///
+    /// Returns `false` if the document is not linearized or if there is not
+    /// enough data available yet to tell.
+    pub fn is_linearized(&self) -> bool {
+        lib().FPDFAvail_IsLinearized(self) == 1
+    }
+
+    /// Returns `true` if enough data is available to load the document.
+    pub fn is_doc_available(&self) -> PdfiumResult<bool> {
+        data_avail_to_result(lib().FPDFAvail_IsDocAvail(self, &mut download_hints()))
+    }
+
+    /// Returns `true` if enough data is available to load the page at `index`.
+    ///
+    /// Only call this after the document has been loaded.
+    pub fn is_page_available(&self, index: i32) -> PdfiumResult<bool> {
+        data_avail_to_result(lib().FPDFAvail_IsPageAvail(self, index, &mut download_hints()))
+    }
+
+    /// Loads the [`PdfiumDocument`] from this [`PdfiumAvailability`].
+    ///
+    /// Returns [`PdfiumError::NotFound`] if not enough data is available yet.
+    pub fn document(&self, password: Option<&str>) -> PdfiumResult<PdfiumDocument> {
+        if !self.is_doc_available()? {
+            return Err(PdfiumError::NotFound);
+        }
+        let password = CString::new(password.unwrap_or(""))?;
+        let handle = lib().FPDFAvail_GetDocument(self, &password);
+        PdfiumDocument::new_from_availability(handle, self.clone())
+    }
 }
 
 impl From<&PdfiumAvailability> for FPDF_AVAIL {
@@ -50,3 +153,48 @@ impl From<&PdfiumAvailability> for FPDF_AVAIL {
 fn close_availability(availability: FPDF_AVAIL) {
     lib().FPDFAvail_Destroy(availability);
 }
+
+/// FX_FILEAVAIL together with the number of available bytes. PDFium passes a pointer
+/// to the first field to the callback, allowing it to retrieve the whole struct.
+#[repr(C)]
+struct PdfiumFileAvail {
+    file_avail: FX_FILEAVAIL,
+    available: Cell<u64>,
+}
+
+/// The callback function invoked by PDFium to check whether a range of bytes is available.
+unsafe extern "C" fn is_data_avail_callback(
+    this: *mut FX_FILEAVAIL,
+    offset: usize,
+    size: usize,
+) -> FPDF_BOOL {
+    let file_avail = unsafe { &*(this as *const PdfiumFileAvail) };
+    let end = (offset as u64).saturating_add(size as u64);
+    (end <= file_avail.available.get()) as FPDF_BOOL
+}
+
+/// The callback function invoked by PDFium to report which data it needs next.
+///
+/// The availability is driven by [`PdfiumAvailability::set_available`], so the
+/// hints are ignored.
+unsafe extern "C" fn add_segment_callback(
+    _this: *mut FX_DOWNLOADHINTS,
+    _offset: usize,
+    _size: usize,
+) {
+}
+
+fn download_hints() -> FX_DOWNLOADHINTS {
+    FX_DOWNLOADHINTS {
+        version: 1,
+        AddSegment: Some(add_segment_callback),
+    }
+}
+
+fn data_avail_to_result(status: i32) -> PdfiumResult<bool> {
+    match status {
+        PDF_DATA_AVAIL => Ok(true),
+        PDF_DATA_NOTAVAIL => Ok(false),
+        _ => Err(PdfiumError::FormatError),
+    }
+}
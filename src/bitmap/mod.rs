@@ -17,7 +17,9 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use image::{DynamicImage, ImageFormat, RgbaImage};
+use std::{marker::PhantomData, path::Path, rc::Rc};
+
+use image::{DynamicImage, GrayImage, ImageFormat, RgbaImage};
 
 use crate::{
     error::{PdfiumError, PdfiumResult},
@@ -30,6 +32,8 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct PdfiumBitmap {
     handle: BitmapHandle,
+    // Declared after `handle`, so the buffer outlives the PDFium bitmap using it
+    buffer: Option<Rc<ExternalBuffer>>,
 }
 
 impl PdfiumBitmap {
@@ -39,6 +43,7 @@ impl PdfiumBitmap {
         } else {
             Ok(Self {
                 handle: Handle::new(handle, Some(close_bitmap)),
+                buffer: None,
             })
         }
     }
@@ -55,6 +60,63 @@ impl PdfiumBitmap {
         )
     }
 
+    /// Creates a new [`PdfiumBitmap`] backed by `buffer`, taking ownership of it.
+    ///
+    /// PDFium renders directly into `buffer`, which holds `height` scan lines of `stride`
+    /// bytes each. This allows handing the pixels to an external renderer or compositor
+    /// without copying. The buffer is freed after the PDFium bitmap is destroyed, when the
+    /// last clone of this [`PdfiumBitmap`] is dropped.
+    ///
+    /// Returns [`PdfiumError::InvalidConfiguration`] if the size is not positive, `stride`
+    /// is too small for `width` pixels of `format`, or `buffer` is smaller than
+    /// `stride * height` bytes.
+    pub fn from_external(
+        width: i32,
+        height: i32,
+        format: PdfiumBitmapFormat,
+        buffer: Box<[u8]>,
+        stride: i32,
+    ) -> PdfiumResult<Self> {
+        check_external_buffer(width, height, format, buffer.len(), stride)?;
+        let buffer = ExternalBuffer(Box::into_raw(buffer));
+        let first_scan = unsafe { &mut *buffer.0 };
+        let mut bitmap = try_lib()?.FPDFBitmap_CreateEx(
+            width,
+            height,
+            format.into(),
+            Some(first_scan),
+            stride,
+        )?;
+        bitmap.buffer = Some(Rc::new(buffer));
+        Ok(bitmap)
+    }
+
+    /// Creates a new [`PdfiumBitmap`] backed by `buffer`, borrowing it.
+    ///
+    /// Like [`PdfiumBitmap::from_external`], but `buffer` stays owned by the caller, so it can
+    /// be reused for many bitmaps without allocations, e.g. one per rendered frame. The
+    /// returned [`PdfiumBorrowedBitmap`] keeps `buffer` borrowed until it is dropped. PDFium
+    /// does not free the buffer.
+    ///
+    /// Returns [`PdfiumError::InvalidConfiguration`] if the size is not positive, `stride`
+    /// is too small for `width` pixels of `format`, or `buffer` is smaller than
+    /// `stride * height` bytes.
+    pub fn from_external_buffer(
+        width: i32,
+        height: i32,
+        format: PdfiumBitmapFormat,
+        buffer: &mut [u8],
+        stride: i32,
+    ) -> PdfiumResult<PdfiumBorrowedBitmap<'_>> {
+        check_external_buffer(width, height, format, buffer.len(), stride)?;
+        let bitmap =
+            try_lib()?.FPDFBitmap_CreateEx(width, height, format.into(), Some(buffer), stride)?;
+        Ok(PdfiumBorrowedBitmap {
+            bitmap,
+            buffer: PhantomData,
+        })
+    }
+
     /// Fills this entire [`PdfiumBitmap`] with the given [`PdfiumColor`].
     pub fn fill(&self, color: &PdfiumColor) -> PdfiumResult<()> {
         let lib = lib();
@@ -71,13 +133,22 @@ impl PdfiumBitmap {
     /// Returns the width of the image in the bitmap buffer backing this [`PdfiumBitmap`].
     #[inline]
     pub fn width(&self) -> i32 {
-        lib().FPDFBitmap_GetWidth(self) as i32
+        lib().FPDFBitmap_GetWidth(self)
     }
 
     /// Returns the height of the image in the bitmap buffer backing this [`PdfiumBitmap`].
     #[inline]
     pub fn height(&self) -> i32 {
-        lib().FPDFBitmap_GetHeight(self) as i32
+        lib().FPDFBitmap_GetHeight(self)
+    }
+
+    /// Returns the number of bytes for each scan line in the bitmap buffer backing this
+    /// [`PdfiumBitmap`].
+    ///
+    /// The stride may be more than `width * bytes per pixel`.
+    #[inline]
+    pub fn stride(&self) -> i32 {
+        lib().FPDFBitmap_GetStride(self)
     }
 
     /// Returns the pixel format of the image in the bitmap buffer backing this [`PdfiumBitmap`].
@@ -96,39 +167,290 @@ impl PdfiumBitmap {
         unsafe { std::slice::from_raw_parts(buffer as *const u8, len as usize) }
     }
 
+    /// Returns a mutable reference to the bitmap buffer backing this [`PdfiumBitmap`].
+    fn as_raw_bytes_mut<'a>(&mut self) -> &'a mut [u8] {
+        let lib = lib();
+        let buffer = lib.FPDFBitmap_GetBuffer(self.handle.handle());
+        let len = lib.FPDFBitmap_GetStride(self) * lib.FPDFBitmap_GetHeight(self);
+        unsafe { std::slice::from_raw_parts_mut(buffer as *mut u8, len as usize) }
+    }
+
+    /// Composites `color` over the region at (`left`, `top`) with the given `width` and
+    /// `height` of this [`PdfiumBitmap`], according to the alpha of `color`.
+    ///
+    /// The region is clipped to the bitmap. Gray bitmaps are blended with the luma of `color`.
+    pub(crate) fn blend_rect(
+        &mut self,
+        left: i32,
+        top: i32,
+        width: i32,
+        height: i32,
+        color: &PdfiumColor,
+    ) -> PdfiumResult<()> {
+        let format = self.format();
+        if matches!(
+            format,
+            PdfiumBitmapFormat::Unknown | PdfiumBitmapFormat::BgraPremul
+        ) {
+            return Err(PdfiumError::UnsupportedImageFormat);
+        }
+        let bytes_per_pixel = format.bytes_per_pixel();
+        let x_range = left.max(0) as usize..(left + width).clamp(0, self.width()) as usize;
+        let y_range = top.max(0) as usize..(top + height).clamp(0, self.height()) as usize;
+        let stride = self.stride() as usize;
+
+        let alpha = color.alpha() as f32 / 255.0;
+        let luma =
+            0.299 * color.red() as f32 + 0.587 * color.green() as f32 + 0.114 * color.blue() as f32;
+        let source = match format {
+            PdfiumBitmapFormat::Gray => vec![luma],
+            _ => vec![
+                color.blue() as f32,
+                color.green() as f32,
+                color.red() as f32,
+            ],
+        };
+        let blend = |dst: u8, src: f32| (src * alpha + dst as f32 * (1.0 - alpha)).round() as u8;
+
+        let buffer = self.as_raw_bytes_mut();
+        for y in y_range {
+            let row = &mut buffer[y * stride..(y + 1) * stride];
+            for x in x_range.clone() {
+                let pixel = &mut row[x * bytes_per_pixel..(x + 1) * bytes_per_pixel];
+                for (dst, src) in pixel.iter_mut().zip(&source) {
+                    *dst = blend(*dst, *src);
+                }
+                if format == PdfiumBitmapFormat::Bgra {
+                    pixel[3] = blend(pixel[3], 255.0);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the color of the pixel at (`x`, `y`) of this [`PdfiumBitmap`].
+    ///
+    /// The pixel is normalized into a [`PdfiumColor`] irrespective of the pixel format, like
+    /// [`PdfiumBitmap::as_rgba_bytes`]. Formats without an alpha channel return opaque colors,
+    /// premultiplied colors are un-premultiplied. Returns
+    /// [`PdfiumError::UnsupportedImageFormat`] for [`PdfiumBitmapFormat::Gray`] and
+    /// [`PdfiumBitmapFormat::Unknown`] bitmaps, and an error if (`x`, `y`) is outside this
+    /// bitmap.
+    pub fn pixel(&self, x: i32, y: i32) -> PdfiumResult<PdfiumColor> {
+        if x < 0 || y < 0 || x >= self.width() || y >= self.height() {
+            return Err(PdfiumError::InvalidConfiguration(
+                "Pixel coordinates must be within the bitmap".to_string(),
+            ));
+        }
+        let format = self.format();
+        if format == PdfiumBitmapFormat::Gray {
+            return Err(PdfiumError::UnsupportedImageFormat);
+        }
+        let bytes_per_pixel = format.bytes_per_pixel();
+        let offset = y as usize * self.stride() as usize + x as usize * bytes_per_pixel;
+        let pixel = &self.as_raw_bytes()[offset..offset + bytes_per_pixel];
+        let [red, green, blue, alpha] = format.to_rgba(pixel)?;
+        Ok(PdfiumColor::new(red, green, blue, alpha))
+    }
+
+    /// Returns a new [`PdfiumBitmap`] containing a copy of the region at (`x`, `y`) with
+    /// the given `width` and `height` of this [`PdfiumBitmap`].
+    ///
+    /// The cropped bitmap has the same pixel format as this bitmap. Returns an error if
+    /// the region is empty or does not fit within this bitmap.
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> PdfiumResult<PdfiumBitmap> {
+        let fits = |start: u32, len: u32, max: i32| {
+            len > 0
+                && start
+                    .checked_add(len)
+                    .is_some_and(|end| end as i64 <= max as i64)
+        };
+        if !fits(x, width, self.width()) || !fits(y, height, self.height()) {
+            return Err(PdfiumError::InvalidConfiguration(
+                "Crop region must be non-empty and within the bitmap".to_string(),
+            ));
+        }
+        let format = self.format();
+        let bytes_per_pixel = format.bytes_per_pixel();
+        if bytes_per_pixel == 0 {
+            return Err(PdfiumError::UnsupportedImageFormat);
+        }
+
+        let mut cropped = PdfiumBitmap::empty(width as i32, height as i32, format)?;
+        let src_stride = self.stride() as usize;
+        let dst_stride = cropped.stride() as usize;
+        let src_start = x as usize * bytes_per_pixel;
+        let row_len = width as usize * bytes_per_pixel;
+        let src = self.as_raw_bytes();
+        let dst = cropped.as_raw_bytes_mut();
+        for (row, dst_row) in dst.chunks_mut(dst_stride).enumerate() {
+            let src_offset = (y as usize + row) * src_stride + src_start;
+            dst_row[..row_len].copy_from_slice(&src[src_offset..src_offset + row_len]);
+        }
+        Ok(cropped)
+    }
+
     /// Returns an owned copy of the bitmap buffer backing this [`PdfiumBitmap`] as RGBA.
     ///
-    /// Normalizing all color channels into RGBA irrespective of the original pixel format.
+    /// Normalizing all color channels into RGBA irrespective of the original pixel format:
+    /// gray values are expanded, formats without alpha become opaque and premultiplied colors
+    /// are un-premultiplied. Padding at the end of each scan line is skipped.
     pub fn as_rgba_bytes(&self) -> PdfiumResult<Vec<u8>> {
-        match self.format() {
-            PdfiumBitmapFormat::Bgra => Ok(self
-                .as_raw_bytes()
-                .chunks_exact(4)
-                .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], pixel[3]]) // B,G,R,A -> R,G,B,A
-                .collect()),
-            PdfiumBitmapFormat::Bgr => Ok(self
-                .as_raw_bytes()
-                .chunks_exact(3)
-                .flat_map(|pixel| [pixel[2], pixel[1], pixel[0], 255]) // B,G,R,A -> R,G,B,A
-                .collect()),
-            PdfiumBitmapFormat::Gray => Ok(self
-                .as_raw_bytes()
-                .chunks_exact(1)
-                .flat_map(|pixel| [pixel[0], pixel[0], pixel[0], 255]) // B,G,R,A -> R,G,B,A
-                .collect()),
-            PdfiumBitmapFormat::Unknown
-            | PdfiumBitmapFormat::Bgrx
-            | PdfiumBitmapFormat::BgraPremul => Err(PdfiumError::UnsupportedImageFormat),
+        Ok(self.rgba_pixels()?.concat())
+    }
+
+    /// Returns per-channel histograms of this [`PdfiumBitmap`], in R, G, B, A order.
+    ///
+    /// Each histogram has 256 buckets counting the number of pixels with that channel value.
+    /// Pixels are normalized into RGBA irrespective of the original pixel format, so bitmaps
+    /// without an alpha channel count all pixels as opaque (255).
+    pub fn histogram(&self) -> PdfiumResult<[[u32; 256]; 4]> {
+        let format = self.format();
+        let bytes_per_pixel = format.bytes_per_pixel();
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+        let stride = self.stride() as usize;
+        if bytes_per_pixel == 0 {
+            return Err(PdfiumError::UnsupportedImageFormat);
+        }
+        let mut histogram = [[0u32; 256]; 4];
+        for row in self.as_raw_bytes().chunks(stride).take(height) {
+            for pixel in row[..width * bytes_per_pixel].chunks_exact(bytes_per_pixel) {
+                let rgba = format.to_rgba(pixel)?;
+                for (channel, value) in rgba.into_iter().enumerate() {
+                    histogram[channel][value as usize] += 1;
+                }
+            }
+        }
+        Ok(histogram)
+    }
+
+    /// Returns `true` if at least a `threshold` fraction (`0.0` - `1.0`) of the color values
+    /// in this [`PdfiumBitmap`] is near white.
+    ///
+    /// Useful for detecting empty (scanned) pages. The alpha channel is ignored, so render
+    /// pages with a white background. Returns `false` if the pixel format is not supported.
+    pub fn is_mostly_blank(&self, threshold: f32) -> bool {
+        const NEAR_WHITE: usize = 240;
+
+        let Ok(histogram) = self.histogram() else {
+            return false;
+        };
+        let (mut white, mut total) = (0u64, 0u64);
+        for channel in &histogram[..3] {
+            white += channel[NEAR_WHITE..].iter().map(|&n| n as u64).sum::<u64>();
+            total += channel.iter().map(|&n| n as u64).sum::<u64>();
+        }
+        total > 0 && white as f64 / total as f64 >= threshold as f64
+    }
+
+    /// Returns `true` if this [`PdfiumBitmap`] and `other` have the same size and pixels.
+    ///
+    /// Pixels are compared after normalizing both bitmaps into RGBA, so bitmaps with different
+    /// pixel formats can still be equal.
+    pub fn pixels_equal(&self, other: &PdfiumBitmap) -> PdfiumResult<bool> {
+        if self.width() != other.width() || self.height() != other.height() {
+            return Ok(false);
+        }
+        Ok(self.rgba_pixels()? == other.rgba_pixels()?)
+    }
+
+    /// Returns the similarity of this [`PdfiumBitmap`] and `other`, from `0.0` to `1.0`.
+    ///
+    /// This is one minus the mean absolute difference of all RGBA color values, normalized to
+    /// `0.0` - `1.0`. Identical bitmaps have a similarity of `1.0`, bitmaps of different sizes
+    /// a similarity of `0.0`. Like [`PdfiumBitmap::pixels_equal`], pixel formats may differ.
+    pub fn similarity(&self, other: &PdfiumBitmap) -> PdfiumResult<f32> {
+        if self.width() != other.width() || self.height() != other.height() {
+            return Ok(0.0);
+        }
+        let (pixels, other_pixels) = (self.rgba_pixels()?, other.rgba_pixels()?);
+        if pixels.is_empty() {
+            return Ok(1.0);
+        }
+        let diff: u64 = pixels
+            .iter()
+            .zip(&other_pixels)
+            .flat_map(|(a, b)| a.iter().zip(b).map(|(a, b)| a.abs_diff(*b) as u64))
+            .sum();
+        let max = pixels.len() as f64 * 4.0 * 255.0;
+        Ok((1.0 - diff as f64 / max) as f32)
+    }
+
+    /// Returns the pixels of this bitmap normalized into RGBA, skipping the stride padding.
+    fn rgba_pixels(&self) -> PdfiumResult<Vec<[u8; 4]>> {
+        let format = self.format();
+        let bytes_per_pixel = format.bytes_per_pixel();
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+        let stride = self.stride() as usize;
+        if bytes_per_pixel == 0 {
+            return Err(PdfiumError::UnsupportedImageFormat);
+        }
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in self.as_raw_bytes().chunks(stride).take(height) {
+            for pixel in row[..width * bytes_per_pixel].chunks_exact(bytes_per_pixel) {
+                pixels.push(format.to_rgba(pixel)?);
+            }
+        }
+        Ok(pixels)
+    }
+
+    /// Returns a copy of this bitmap as an RGBA [`ndarray::Array3`] with shape `(height, width, 4)`.
+    ///
+    /// Normalizing all color channels into RGBA irrespective of the original pixel format.
+    /// Padding at the end of each scan line (see [`PdfiumBitmap::stride`]) is skipped.
+    #[cfg(feature = "ndarray")]
+    pub fn to_ndarray(&self) -> PdfiumResult<ndarray::Array3<u8>> {
+        self.to_ndarray_with_channels(4)
+    }
+
+    /// Returns a copy of this bitmap as an RGB [`ndarray::Array3`] with shape `(height, width, 3)`.
+    ///
+    /// Same as [`PdfiumBitmap::to_ndarray`], but with the alpha channel dropped.
+    #[cfg(feature = "ndarray")]
+    pub fn to_rgb_ndarray(&self) -> PdfiumResult<ndarray::Array3<u8>> {
+        self.to_ndarray_with_channels(3)
+    }
+
+    #[cfg(feature = "ndarray")]
+    fn to_ndarray_with_channels(&self, channels: usize) -> PdfiumResult<ndarray::Array3<u8>> {
+        let format = self.format();
+        let bytes_per_pixel = format.bytes_per_pixel();
+        let width = self.width() as usize;
+        let height = self.height() as usize;
+        let stride = self.stride() as usize;
+        if bytes_per_pixel == 0 {
+            return Err(PdfiumError::UnsupportedImageFormat);
+        }
+        let mut data = Vec::with_capacity(width * height * channels);
+        for row in self.as_raw_bytes().chunks(stride).take(height) {
+            for pixel in row[..width * bytes_per_pixel].chunks_exact(bytes_per_pixel) {
+                let rgba = format.to_rgba(pixel)?;
+                data.extend_from_slice(&rgba[..channels]);
+            }
         }
+        ndarray::Array3::from_shape_vec((height, width, channels), data)
+            .or(Err(PdfiumError::ImageError))
     }
 
     /// Returns a copy of this a bitmap as a [`DynamicImage::ImageRgba8`]
     pub fn as_rgba8_image(&self) -> PdfiumResult<DynamicImage> {
+        Ok(DynamicImage::ImageRgba8(self.as_rgba_image()?))
+    }
+
+    /// Returns a copy of this bitmap as an [`RgbaImage`]
+    pub fn as_rgba_image(&self) -> PdfiumResult<RgbaImage> {
         let rgba_bytes = self.as_rgba_bytes()?;
-        match RgbaImage::from_raw(self.width() as u32, self.height() as u32, rgba_bytes) {
-            Some(image) => Ok(DynamicImage::ImageRgba8(image)),
-            None => Err(PdfiumError::ImageError),
-        }
+        RgbaImage::from_raw(self.width() as u32, self.height() as u32, rgba_bytes)
+            .ok_or(PdfiumError::ImageError)
+    }
+
+    /// Returns a copy of this bitmap as a grayscale [`GrayImage`]
+    ///
+    /// Color bitmaps are converted to luma, dropping the alpha channel.
+    pub fn as_luma_image(&self) -> PdfiumResult<GrayImage> {
+        Ok(DynamicImage::ImageRgba8(self.as_rgba_image()?).into_luma8())
     }
 
     /// Returns a copy of this a bitmap as a [`DynamicImage::ImageRgb8`]
@@ -139,10 +461,85 @@ impl PdfiumBitmap {
             .into())
     }
 
+    /// Returns a copy of this bitmap as a [`DynamicImage::ImageRgba8`] with the red, green and
+    /// blue channels inverted, leaving alpha as is.
+    ///
+    /// This is a quick way to produce a dark preview of a rendered page. Note that images and
+    /// colored content are inverted as well.
+    pub fn inverted(&self) -> PdfiumResult<DynamicImage> {
+        let mut rgba_bytes = self.as_rgba_bytes()?;
+        for pixel in rgba_bytes.chunks_exact_mut(4) {
+            for channel in &mut pixel[..3] {
+                *channel = 255 - *channel;
+            }
+        }
+        match RgbaImage::from_raw(self.width() as u32, self.height() as u32, rgba_bytes) {
+            Some(image) => Ok(DynamicImage::ImageRgba8(image)),
+            None => Err(PdfiumError::ImageError),
+        }
+    }
+
+    /// Creates a new [`PdfiumBitmap`] containing a copy of `image`.
+    ///
+    /// Images with an alpha channel become [`PdfiumBitmapFormat::Bgra`] bitmaps, all other
+    /// images become [`PdfiumBitmapFormat::Bgr`] bitmaps.
+    pub fn from_image(image: &DynamicImage) -> PdfiumResult<Self> {
+        let (width, height) = (image.width() as i32, image.height() as i32);
+        let (format, pixels) = if image.color().has_alpha() {
+            (PdfiumBitmapFormat::Bgra, image.to_rgba8().into_raw())
+        } else {
+            (PdfiumBitmapFormat::Bgr, image.to_rgb8().into_raw())
+        };
+        let mut bitmap = PdfiumBitmap::empty(width, height, format)?;
+        let bytes_per_pixel = format.bytes_per_pixel();
+        let row_len = width as usize * bytes_per_pixel;
+        let stride = bitmap.stride() as usize;
+        let buffer = bitmap.as_raw_bytes_mut();
+        for (row, src_row) in pixels.chunks_exact(row_len).enumerate() {
+            let dst_row = &mut buffer[row * stride..row * stride + row_len];
+            for (dst, src) in dst_row
+                .chunks_exact_mut(bytes_per_pixel)
+                .zip(src_row.chunks_exact(bytes_per_pixel))
+            {
+                dst.copy_from_slice(src);
+                dst.swap(0, 2); // R,G,B(,A) -> B,G,R(,A)
+            }
+        }
+        Ok(bitmap)
+    }
+
     /// Saves this bitmap to the given path.
     ///
     /// Include alpha channel only if the [`ImageFormat`] supports it.
     pub fn save(&self, path: &str, format: ImageFormat) -> PdfiumResult<()> {
+        self.save_to_path(Path::new(path), format)
+    }
+
+    /// Saves this bitmap to the given path, inferring the [`ImageFormat`] from its extension.
+    ///
+    /// Recognizes `png`, `jpg`/`jpeg`, `webp`, `bmp` and `tif`/`tiff` (case insensitive).
+    /// Returns [`PdfiumError::UnsupportedImageFormat`] for other or missing extensions. Only
+    /// PNG and JPEG encoders are enabled by default, TIFF is enabled with the `tiff` feature.
+    /// Other formats return [`PdfiumError::ImageError`] unless the `image` crate is built
+    /// with their features.
+    pub fn save_auto(&self, path: impl AsRef<Path>) -> PdfiumResult<()> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase());
+        let format = match extension.as_deref() {
+            Some("png") => ImageFormat::Png,
+            Some("jpg") | Some("jpeg") => ImageFormat::Jpeg,
+            Some("webp") => ImageFormat::WebP,
+            Some("bmp") => ImageFormat::Bmp,
+            Some("tif") | Some("tiff") => ImageFormat::Tiff,
+            _ => return Err(PdfiumError::UnsupportedImageFormat),
+        };
+        self.save_to_path(path, format)
+    }
+
+    fn save_to_path(&self, path: &Path, format: ImageFormat) -> PdfiumResult<()> {
         let image = if format == ImageFormat::Png {
             self.as_rgba8_image()?
         } else {
@@ -161,6 +558,110 @@ impl From<&PdfiumBitmap> for FPDF_BITMAP {
     }
 }
 
+/// Checks the arguments of [PdfiumBitmap::from_external] and
+/// [PdfiumBitmap::from_external_buffer].
+fn check_external_buffer(
+    width: i32,
+    height: i32,
+    format: PdfiumBitmapFormat,
+    len: usize,
+    stride: i32,
+) -> PdfiumResult<()> {
+    let bytes_per_pixel = format.bytes_per_pixel();
+    if width <= 0 || height <= 0 || bytes_per_pixel == 0 {
+        return Err(PdfiumError::InvalidConfiguration(
+            "Bitmap size must be positive and its format known".to_string(),
+        ));
+    }
+    if (stride as i64) < width as i64 * bytes_per_pixel as i64 {
+        return Err(PdfiumError::InvalidConfiguration(
+            "Stride is too small for the bitmap width".to_string(),
+        ));
+    }
+    if (len as i64) < stride as i64 * height as i64 {
+        return Err(PdfiumError::InvalidConfiguration(
+            "Buffer is smaller than stride * height".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// A bitmap rendering into a buffer borrowed from the caller
+///
+/// Returned by [`PdfiumBitmap::from_external_buffer`] and rendered into with
+/// [`PdfiumPage::render_into`](crate::PdfiumPage::render_into). The buffer stays borrowed until
+/// this [`PdfiumBorrowedBitmap`] is dropped. Unlike a [`PdfiumBitmap`] it cannot be cloned and
+/// only forwards the methods below, so the bitmap cannot outlive the borrowed buffer.
+#[derive(Debug)]
+pub struct PdfiumBorrowedBitmap<'a> {
+    bitmap: PdfiumBitmap,
+    buffer: PhantomData<&'a mut [u8]>,
+}
+
+impl PdfiumBorrowedBitmap<'_> {
+    /// Fills this entire bitmap with the given [`PdfiumColor`], see [`PdfiumBitmap::fill`].
+    pub fn fill(&self, color: &PdfiumColor) -> PdfiumResult<()> {
+        self.bitmap.fill(color)
+    }
+
+    /// Returns the width of this bitmap, see [`PdfiumBitmap::width`].
+    #[inline]
+    pub fn width(&self) -> i32 {
+        self.bitmap.width()
+    }
+
+    /// Returns the height of this bitmap, see [`PdfiumBitmap::height`].
+    #[inline]
+    pub fn height(&self) -> i32 {
+        self.bitmap.height()
+    }
+
+    /// Returns the number of bytes for each scan line, see [`PdfiumBitmap::stride`].
+    #[inline]
+    pub fn stride(&self) -> i32 {
+        self.bitmap.stride()
+    }
+
+    /// Returns the pixel format of this bitmap, see [`PdfiumBitmap::format`].
+    #[inline]
+    pub fn format(&self) -> PdfiumBitmapFormat {
+        self.bitmap.format()
+    }
+
+    /// Returns an immutable reference to the borrowed buffer, see
+    /// [`PdfiumBitmap::as_raw_bytes`].
+    pub fn as_raw_bytes(&self) -> &[u8] {
+        self.bitmap.as_raw_bytes()
+    }
+
+    /// Returns the color of the pixel at (`x`, `y`), see [`PdfiumBitmap::pixel`].
+    pub fn pixel(&self, x: i32, y: i32) -> PdfiumResult<PdfiumColor> {
+        self.bitmap.pixel(x, y)
+    }
+
+    /// Returns an owned copy of this bitmap as RGBA, see [`PdfiumBitmap::as_rgba_bytes`].
+    pub fn as_rgba_bytes(&self) -> PdfiumResult<Vec<u8>> {
+        self.bitmap.as_rgba_bytes()
+    }
+
+    /// Returns the bitmap itself, which must not be cloned or leave this crate.
+    pub(crate) fn bitmap(&self) -> &PdfiumBitmap {
+        &self.bitmap
+    }
+}
+
+/// Buffer allocated in Rust backing a [PdfiumBitmap], see [PdfiumBitmap::from_external].
+///
+/// Kept as a raw pointer because PDFium writes into it while it is shared.
+#[derive(Debug)]
+struct ExternalBuffer(*mut [u8]);
+
+impl Drop for ExternalBuffer {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(self.0) });
+    }
+}
+
 /// Closes this [PdfiumBitmap], releasing held memory.
 fn close_bitmap(bitmap: FPDF_BITMAP) {
     lib().FPDFBitmap_Destroy(bitmap);
@@ -185,6 +686,49 @@ pub enum PdfiumBitmapFormat {
     BgraPremul = pdfium_constants::FPDFBitmap_BGRA_Premul,
 }
 
+impl PdfiumBitmapFormat {
+    /// Returns the number of bytes used to store a single pixel in this format.
+    ///
+    /// Returns `0` for [`PdfiumBitmapFormat::Unknown`].
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            PdfiumBitmapFormat::Unknown => 0,
+            PdfiumBitmapFormat::Gray => 1,
+            PdfiumBitmapFormat::Bgr => 3,
+            PdfiumBitmapFormat::Bgrx
+            | PdfiumBitmapFormat::Bgra
+            | PdfiumBitmapFormat::BgraPremul => 4,
+        }
+    }
+
+    /// Normalizes a single `pixel` in this format into RGBA.
+    ///
+    /// Returns [`PdfiumError::UnsupportedImageFormat`] for [`PdfiumBitmapFormat::Unknown`].
+    fn to_rgba(self, pixel: &[u8]) -> PdfiumResult<[u8; 4]> {
+        match self {
+            PdfiumBitmapFormat::Bgra => Ok([pixel[2], pixel[1], pixel[0], pixel[3]]),
+            PdfiumBitmapFormat::Bgr | PdfiumBitmapFormat::Bgrx => {
+                Ok([pixel[2], pixel[1], pixel[0], 255])
+            }
+            PdfiumBitmapFormat::Gray => Ok([pixel[0], pixel[0], pixel[0], 255]),
+            PdfiumBitmapFormat::BgraPremul => {
+                let alpha = pixel[3];
+                let unpremultiply = |value: u8| match alpha {
+                    0 => 0,
+                    _ => ((value as u32 * 255 + alpha as u32 / 2) / alpha as u32).min(255) as u8,
+                };
+                Ok([
+                    unpremultiply(pixel[2]),
+                    unpremultiply(pixel[1]),
+                    unpremultiply(pixel[0]),
+                    alpha,
+                ])
+            }
+            PdfiumBitmapFormat::Unknown => Err(PdfiumError::UnsupportedImageFormat),
+        }
+    }
+}
+
 impl From<i32> for PdfiumBitmapFormat {
     fn from(value: i32) -> Self {
         match value {
@@ -213,6 +757,7 @@ impl From<PdfiumBitmapFormat> for i32 {
 
 #[cfg(test)]
 mod tests {
+    use crate::test_util::filled_rect;
     use crate::*;
 
     #[test]
@@ -237,4 +782,347 @@ mod tests {
             .save("groningen-page-2.png", image::ImageFormat::Png)
             .unwrap();
     }
+
+    #[test]
+    fn test_from_external() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 40.0, 20.0).unwrap();
+        let rect = filled_rect(0.0, 0.0, 20.0, 20.0, 255, 0, 0);
+        page.insert_object_at(rect, 0).unwrap();
+        page.generate_content().unwrap();
+
+        let (width, height, stride) = (40, 20, 40 * 4 + 16);
+        let buffer = vec![0u8; (stride * height) as usize].into_boxed_slice();
+        let address = buffer.as_ptr();
+        let bitmap =
+            PdfiumBitmap::from_external(width, height, PdfiumBitmapFormat::Bgra, buffer, stride)
+                .unwrap();
+        assert_eq!(bitmap.stride(), stride);
+        bitmap.fill(&PdfiumColor::WHITE).unwrap();
+        lib().FPDF_RenderPageBitmap(&bitmap, &page, 0, 0, width, height, 0, 0);
+
+        // The clone keeps the buffer alive after the original is dropped
+        let clone = bitmap.clone();
+        drop(bitmap);
+        let bytes = clone.as_raw_bytes();
+        assert_eq!(bytes.as_ptr(), address);
+        assert_eq!(&bytes[..4], &[0, 0, 255, 255]); // Red, in BGRA
+        let right = (stride * 10 + 30 * 4) as usize;
+        assert_eq!(&bytes[right..right + 4], &[255, 255, 255, 255]);
+
+        let small = vec![0u8; 10].into_boxed_slice();
+        assert!(PdfiumBitmap::from_external(4, 4, PdfiumBitmapFormat::Bgra, small, 16).is_err());
+        let buffer = vec![0u8; 64].into_boxed_slice();
+        assert!(PdfiumBitmap::from_external(4, 4, PdfiumBitmapFormat::Bgra, buffer, 8).is_err());
+    }
+
+    #[test]
+    fn test_inverted() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        let rect = filled_rect(25.0, 25.0, 50.0, 50.0, 255, 0, 0);
+        page.insert_object_at(rect, 0).unwrap();
+
+        let config = PdfiumRenderConfig::new()
+            .with_width(100)
+            .with_background(PdfiumColor::WHITE);
+        let bitmap = page.render(&config).unwrap();
+        let inverted = bitmap.inverted().unwrap().into_rgba8();
+        assert_eq!(inverted.width() as i32, bitmap.width());
+        assert_eq!(inverted.get_pixel(5, 5).0, [0, 0, 0, 255]);
+        assert_eq!(inverted.get_pixel(50, 50).0, [0, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_from_image() {
+        let mut rgba = image::RgbaImage::new(4, 2);
+        for (x, y, pixel) in rgba.enumerate_pixels_mut() {
+            *pixel = image::Rgba([x as u8 * 60, y as u8 * 100, 7, 200]);
+        }
+        let image = image::DynamicImage::ImageRgba8(rgba);
+        let bitmap = PdfiumBitmap::from_image(&image).unwrap();
+        assert_eq!(bitmap.format(), PdfiumBitmapFormat::Bgra);
+        assert_eq!(bitmap.as_rgba8_image().unwrap(), image);
+
+        let image = image::DynamicImage::ImageRgb8(image.to_rgb8());
+        let bitmap = PdfiumBitmap::from_image(&image).unwrap();
+        assert_eq!(bitmap.format(), PdfiumBitmapFormat::Bgr);
+        assert_eq!(bitmap.as_rgb8_image().unwrap(), image);
+    }
+
+    #[test]
+    fn test_as_rgba_and_luma_image() {
+        let bitmap = PdfiumBitmap::empty(40, 30, PdfiumBitmapFormat::Bgra).unwrap();
+        bitmap.fill(&PdfiumColor::new(255, 255, 255, 255)).unwrap();
+        let rgba = bitmap.as_rgba_image().unwrap();
+        assert_eq!(rgba.dimensions(), (40, 30));
+        assert_eq!(rgba.get_pixel(5, 5).0, [255, 255, 255, 255]);
+
+        let gray = PdfiumBitmap::empty(40, 30, PdfiumBitmapFormat::Gray).unwrap();
+        gray.fill(&PdfiumColor::BLACK).unwrap();
+        let luma = gray.as_luma_image().unwrap();
+        assert_eq!(luma.dimensions(), (40, 30));
+        assert_eq!(luma.get_pixel(5, 5).0, [0]);
+        assert_eq!(bitmap.as_luma_image().unwrap().get_pixel(5, 5).0, [255]);
+    }
+
+    #[test]
+    fn test_save_auto() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let config = PdfiumRenderConfig::new().with_width(200);
+        let bitmap = page.render(&config).unwrap();
+
+        bitmap.save_auto("groningen-auto.png").unwrap();
+        let bytes = std::fs::read("groningen-auto.png").unwrap();
+        assert_eq!(&bytes[..8], b"\x89PNG\r\n\x1a\n");
+
+        bitmap.save_auto("groningen-auto.JPG").unwrap();
+        let bytes = std::fs::read("groningen-auto.JPG").unwrap();
+        assert_eq!(&bytes[..3], &[0xff, 0xd8, 0xff]);
+
+        assert!(matches!(
+            bitmap.save_auto("groningen-auto.xyz"),
+            Err(PdfiumError::UnsupportedImageFormat)
+        ));
+        assert!(matches!(
+            bitmap.save_auto("groningen-auto"),
+            Err(PdfiumError::UnsupportedImageFormat)
+        ));
+    }
+
+    #[test]
+    fn test_crop() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let config = PdfiumRenderConfig::new().with_width(800);
+        let bitmap = page.render(&config).unwrap();
+        let (width, height) = (bitmap.width() as u32, bitmap.height() as u32);
+
+        let cropped = bitmap.crop(0, 0, width / 2, height / 2).unwrap();
+        assert_eq!(cropped.width() as u32, width / 2);
+        assert_eq!(cropped.height() as u32, height / 2);
+        assert_eq!(cropped.format(), bitmap.format());
+
+        // First pixel of the second row matches the source
+        let src = bitmap.as_raw_bytes();
+        let dst = cropped.as_raw_bytes();
+        let (src_stride, dst_stride) = (bitmap.stride() as usize, cropped.stride() as usize);
+        assert_eq!(
+            dst[dst_stride..dst_stride + 4],
+            src[src_stride..src_stride + 4]
+        );
+
+        assert!(bitmap.crop(width / 2, 0, width / 2 + 1, 10).is_err());
+        assert!(bitmap.crop(0, 0, 0, 10).is_err());
+        assert!(bitmap.crop(u32::MAX, 0, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        let config = PdfiumRenderConfig::new()
+            .with_width(100)
+            .with_background(PdfiumColor::WHITE);
+        let bitmap = page.render(&config).unwrap();
+        let pixels = (bitmap.width() * bitmap.height()) as u32;
+
+        let histogram = bitmap.histogram().unwrap();
+        for channel in &histogram {
+            assert_eq!(channel[255], pixels);
+        }
+        assert!(bitmap.is_mostly_blank(0.99));
+
+        let bitmap = page
+            .render(&config.with_background(PdfiumColor::BLACK))
+            .unwrap();
+        assert_eq!(bitmap.histogram().unwrap()[0][0], pixels);
+        assert!(!bitmap.is_mostly_blank(0.5));
+    }
+
+    #[test]
+    fn test_pixels_equal_and_similarity() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        let rect = filled_rect(10.0, 10.0, 30.0, 60.0, 0, 0, 255);
+        page.insert_object_at(rect, 0).unwrap();
+        page.generate_content().unwrap();
+
+        let config = PdfiumRenderConfig::new()
+            .with_width(100)
+            .with_format(PdfiumBitmapFormat::Bgra)
+            .with_background(PdfiumColor::WHITE);
+        let bitmap = page.render(&config).unwrap();
+        let rerender = page.render(&config).unwrap();
+        assert!(bitmap.pixels_equal(&rerender).unwrap());
+        assert_eq!(bitmap.similarity(&rerender).unwrap(), 1.0);
+
+        let bgr = page
+            .render(&config.clone().with_format(PdfiumBitmapFormat::Bgr))
+            .unwrap();
+        assert!(bitmap.pixels_equal(&bgr).unwrap());
+
+        lib().FPDFPage_SetRotation(&page, 1);
+        let rotated = page.render(&config).unwrap();
+        assert!(!bitmap.pixels_equal(&rotated).unwrap());
+        let similarity = bitmap.similarity(&rotated).unwrap();
+        assert!(similarity > 0.5 && similarity < 1.0);
+
+        let smaller = page.render(&config.with_width(50)).unwrap();
+        assert!(!bitmap.pixels_equal(&smaller).unwrap());
+        assert_eq!(bitmap.similarity(&smaller).unwrap(), 0.0);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_to_ndarray() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let config = PdfiumRenderConfig::new().with_width(333);
+        let bitmap = page.render(&config).unwrap();
+        let array = bitmap.to_ndarray().unwrap();
+        assert_eq!(
+            array.shape(),
+            &[bitmap.height() as usize, bitmap.width() as usize, 4]
+        );
+        let array = bitmap.to_rgb_ndarray().unwrap();
+        assert_eq!(
+            array.shape(),
+            &[bitmap.height() as usize, bitmap.width() as usize, 3]
+        );
+    }
+
+    #[test]
+    fn test_pixel() {
+        // 2 x 2 pixels with 4 bytes of padding after each line
+        let bitmap = |format: PdfiumBitmapFormat, pixels: [[u8; 4]; 4]| {
+            let bytes_per_pixel = format.bytes_per_pixel();
+            let stride = 2 * bytes_per_pixel + 4;
+            let mut buffer = vec![0u8; 2 * stride];
+            for (index, pixel) in pixels.iter().enumerate() {
+                let offset = (index / 2) * stride + (index % 2) * bytes_per_pixel;
+                buffer[offset..offset + bytes_per_pixel].copy_from_slice(&pixel[..bytes_per_pixel]);
+            }
+            PdfiumBitmap::from_external(2, 2, format, buffer.into_boxed_slice(), stride as i32)
+                .unwrap()
+        };
+        let pixels = [
+            [10, 20, 30, 255],
+            [40, 50, 60, 128],
+            [0, 0, 0, 0],
+            [64, 32, 128, 128],
+        ];
+
+        let bgra = bitmap(PdfiumBitmapFormat::Bgra, pixels);
+        assert_eq!(bgra.pixel(0, 0).unwrap(), PdfiumColor::new(30, 20, 10, 255));
+        assert_eq!(bgra.pixel(1, 0).unwrap(), PdfiumColor::new(60, 50, 40, 128));
+        assert_eq!(
+            bgra.pixel(1, 1).unwrap(),
+            PdfiumColor::new(128, 32, 64, 128)
+        );
+
+        let bgr = bitmap(PdfiumBitmapFormat::Bgr, pixels);
+        assert_eq!(bgr.pixel(1, 0).unwrap(), PdfiumColor::new(60, 50, 40, 255));
+        assert_eq!(bgr.pixel(0, 1).unwrap(), PdfiumColor::new(0, 0, 0, 255));
+
+        let bgrx = bitmap(PdfiumBitmapFormat::Bgrx, pixels);
+        assert_eq!(
+            bgrx.pixel(1, 1).unwrap(),
+            PdfiumColor::new(128, 32, 64, 255)
+        );
+
+        let premul = bitmap(PdfiumBitmapFormat::BgraPremul, pixels);
+        assert_eq!(
+            premul.pixel(0, 0).unwrap(),
+            PdfiumColor::new(30, 20, 10, 255)
+        );
+        assert_eq!(premul.pixel(0, 1).unwrap(), PdfiumColor::new(0, 0, 0, 0));
+        assert_eq!(
+            premul.pixel(1, 1).unwrap(),
+            PdfiumColor::new(255, 64, 128, 128)
+        );
+
+        assert!(bgra.pixel(2, 0).is_err());
+        assert!(bgra.pixel(0, -1).is_err());
+        let gray = PdfiumBitmap::empty(2, 2, PdfiumBitmapFormat::Gray).unwrap();
+        assert!(matches!(
+            gray.pixel(0, 0),
+            Err(PdfiumError::UnsupportedImageFormat)
+        ));
+    }
+
+    #[test]
+    fn test_as_rgba_bytes_all_formats() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let config = PdfiumRenderConfig::new()
+            .with_width(200)
+            .with_format(PdfiumBitmapFormat::Gray);
+        let gray = page.render(&config).unwrap();
+        let rgba = gray.as_rgba_bytes().unwrap();
+        assert_eq!(rgba.len(), (gray.width() * gray.height() * 4) as usize);
+        assert!(rgba
+            .chunks_exact(4)
+            .all(|p| p[0] == p[1] && p[1] == p[2] && p[3] == 255));
+        gray.save("groningen-gray.png", image::ImageFormat::Png)
+            .unwrap();
+        let saved = image::open("groningen-gray.png").unwrap();
+        assert_eq!(saved.width(), 200);
+
+        // Padded 2 x 1 bitmaps in the 32-bit formats without plain alpha
+        let bitmap = |format: PdfiumBitmapFormat| {
+            let buffer = vec![10, 20, 30, 128, 64, 32, 128, 128, 0, 0, 0, 0].into_boxed_slice();
+            PdfiumBitmap::from_external(2, 1, format, buffer, 12).unwrap()
+        };
+        assert_eq!(
+            bitmap(PdfiumBitmapFormat::Bgrx).as_rgba_bytes().unwrap(),
+            [30, 20, 10, 255, 128, 32, 64, 255]
+        );
+        assert_eq!(
+            bitmap(PdfiumBitmapFormat::BgraPremul)
+                .as_rgba_bytes()
+                .unwrap(),
+            [60, 40, 20, 128, 255, 64, 128, 128]
+        );
+    }
+
+    #[test]
+    fn test_from_external_buffer() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 40.0, 20.0).unwrap();
+        let rect = filled_rect(0.0, 0.0, 20.0, 20.0, 255, 0, 0);
+        page.insert_object_at(rect, 0).unwrap();
+        page.generate_content().unwrap();
+
+        let (width, height, stride) = (40, 20, 40 * 4);
+        let mut buffer = vec![0u8; (stride * height) as usize];
+        let address = buffer.as_ptr();
+        for frame in 0..2 {
+            {
+                let bitmap = PdfiumBitmap::from_external_buffer(
+                    width,
+                    height,
+                    PdfiumBitmapFormat::Bgra,
+                    &mut buffer,
+                    stride,
+                )
+                .unwrap();
+                assert_eq!(bitmap.as_raw_bytes().as_ptr(), address);
+                let config = PdfiumRenderConfig::new().with_width(width);
+                page.render_into(&bitmap, &config).unwrap();
+            }
+            // The buffer is still owned here and holds the rendered frame
+            assert_eq!(&buffer[..4], &[0, 0, 255, 255], "frame {frame}"); // Red, in BGRA
+            let right = (stride * 10 + 30 * 4) as usize;
+            assert_eq!(&buffer[right..right + 4], &[255, 255, 255, 255]);
+            buffer.fill(0);
+        }
+
+        let mut small = vec![0u8; 10];
+        assert!(
+            PdfiumBitmap::from_external_buffer(4, 4, PdfiumBitmapFormat::Bgra, &mut small, 16)
+                .is_err()
+        );
+    }
 }
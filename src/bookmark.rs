@@ -17,15 +17,21 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::{collections::HashSet, iter};
+
 use crate::{
+    c_api::utf16::utf16le_to_string,
     error::{PdfiumError, PdfiumResult},
+    lib,
     pdfium_types::{BookmarkHandle, Handle, FPDF_BOOKMARK},
+    PdfiumAction, PdfiumDestination, PdfiumDocument,
 };
 
 /// # Rust interface to FPDF_BOOKMARK
 #[derive(Debug, Clone)]
 pub struct PdfiumBookmark {
     handle: BookmarkHandle,
+    owner: Option<PdfiumDocument>,
 }
 
 impl PdfiumBookmark {
@@ -35,9 +41,84 @@ impl PdfiumBookmark {
         } else {
             Ok(Self {
                 handle: Handle::new(handle, None), // TODO: check close is not needed
+                owner: None,
             })
         }
     }
+
+    pub(crate) fn set_owner(&mut self, owner: PdfiumDocument) {
+        self.owner = Some(owner);
+    }
+
+    /// Returns the title of this [`PdfiumBookmark`].
+    pub fn title(&self) -> String {
+        let lib = lib();
+        let len = lib.FPDFBookmark_GetTitle(self, None, 0);
+        let mut buffer = vec![0u8; len as usize];
+        lib.FPDFBookmark_GetTitle(self, Some(&mut buffer), len);
+        utf16le_to_string(&buffer)
+    }
+
+    /// Returns the first child of this [`PdfiumBookmark`], if any.
+    ///
+    /// A malformed outline may refer back to an ancestor. Use [`PdfiumBookmark::children`] or
+    /// [`PdfiumDocument::bookmarks`] to walk the outline without following such cycles.
+    pub fn first_child(&self) -> Option<PdfiumBookmark> {
+        let owner = self.owner.as_ref()?;
+        owner.bookmark_child(Some(self))
+    }
+
+    /// Returns the next sibling of this [`PdfiumBookmark`], if any.
+    ///
+    /// A malformed outline may refer back to an earlier sibling, see
+    /// [`PdfiumBookmark::first_child`].
+    pub fn next_sibling(&self) -> Option<PdfiumBookmark> {
+        let owner = self.owner.as_ref()?;
+        let mut sibling = lib().FPDFBookmark_GetNextSibling(owner, self).ok()?;
+        sibling.set_owner(owner.clone());
+        Some(sibling)
+    }
+
+    /// Returns an iterator over the direct children of this [`PdfiumBookmark`].
+    ///
+    /// Iteration stops when a child repeats, to guard against circular sibling references.
+    pub fn children(&self) -> impl Iterator<Item = PdfiumBookmark> {
+        let mut visited = HashSet::new();
+        iter::successors(self.first_child(), PdfiumBookmark::next_sibling)
+            .take_while(move |child| visited.insert(FPDF_BOOKMARK::from(child)))
+    }
+
+    /// Returns the action of this [`PdfiumBookmark`], if any.
+    ///
+    /// Bookmarks without an action usually have a [`PdfiumBookmark::destination`] instead.
+    pub fn action(&self) -> Option<PdfiumAction> {
+        lib().FPDFBookmark_GetAction(self).ok()
+    }
+
+    /// Returns the direct destination of this [`PdfiumBookmark`], if any.
+    ///
+    /// Bookmarks without a destination usually have a [`PdfiumBookmark::action`] instead.
+    pub fn destination(&self) -> Option<PdfiumDestination> {
+        let owner = self.owner.as_ref()?;
+        lib().FPDFBookmark_GetDest(owner, self).ok()
+    }
+
+    /// Returns the zero-based index of the page this [`PdfiumBookmark`] points to.
+    ///
+    /// Both a direct destination and a "GoTo" action are supported. Returns `None` if the
+    /// bookmark does not point to a page in this document.
+    pub fn page_index(&self) -> Option<i32> {
+        let owner = self.owner.as_ref()?;
+        let lib = lib();
+        let dest = self.destination().or_else(|| {
+            let action = self.action()?;
+            lib.FPDFAction_GetDest(owner, &action).ok()
+        });
+        match lib.FPDFDest_GetDestPageIndex(owner, &dest?) {
+            index if index >= 0 => Some(index),
+            _ => None,
+        }
+    }
 }
 
 impl From<&PdfiumBookmark> for FPDF_BOOKMARK {
@@ -45,3 +126,84 @@ impl From<&PdfiumBookmark> for FPDF_BOOKMARK {
         bookmark.handle.handle()
     }
 }
+
+/// Depth-first iterator over all [`PdfiumBookmark`]s of a document
+///
+/// Yields `(depth, bookmark)` pairs in outline order, where top-level bookmarks have depth 0.
+/// Each bookmark is yielded at most once, so circular references in a malformed outline do
+/// not cause an endless loop. See [`PdfiumDocument::bookmarks`].
+pub struct PdfiumBookmarks {
+    stack: Vec<(i32, PdfiumBookmark)>,
+    visited: HashSet<FPDF_BOOKMARK>,
+}
+
+impl PdfiumBookmarks {
+    pub(crate) fn new(first: Option<PdfiumBookmark>) -> Self {
+        Self {
+            stack: first.map(|bookmark| (0, bookmark)).into_iter().collect(),
+            visited: HashSet::new(),
+        }
+    }
+}
+
+impl Iterator for PdfiumBookmarks {
+    type Item = (i32, PdfiumBookmark);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((depth, bookmark)) = self.stack.pop() {
+            if !self.visited.insert(FPDF_BOOKMARK::from(&bookmark)) {
+                continue;
+            }
+            // Push the sibling first, so all children are visited before it
+            if let Some(sibling) = bookmark.next_sibling() {
+                self.stack.push((depth, sibling));
+            }
+            if let Some(child) = bookmark.first_child() {
+                self.stack.push((depth + 1, child));
+            }
+            return Some((depth, bookmark));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_bookmarks_iterator() {
+        let document = PdfiumDocument::new_from_path("resources/outline.pdf", None).unwrap();
+        let bookmarks: Vec<(i32, String)> = document
+            .bookmarks()
+            .map(|(depth, bookmark)| (depth, bookmark.title()))
+            .collect();
+        assert_eq!(
+            bookmarks,
+            [
+                (0, "Chapter 1".to_string()),
+                (1, "Section 1.1".to_string()),
+                (1, "Section \"1.2\"".to_string()),
+                (0, "Appendix".to_string()),
+            ]
+        );
+        assert_eq!(PdfiumDocument::new().unwrap().bookmarks().count(), 0);
+    }
+
+    #[test]
+    fn test_bookmark_children_and_targets() {
+        let document = PdfiumDocument::new_from_path("resources/outline.pdf", None).unwrap();
+        let chapter = document.first_bookmark().unwrap();
+        let titles: Vec<String> = chapter.children().map(|child| child.title()).collect();
+        assert_eq!(titles, ["Section 1.1", "Section \"1.2\""]);
+        assert!(chapter.destination().is_some());
+        assert!(chapter.action().is_none());
+        let section = chapter.children().last().unwrap();
+        assert!(section.destination().is_none());
+        assert!(section.action().is_some());
+
+        let appendix = chapter.next_sibling().unwrap();
+        assert_eq!(appendix.children().count(), 0);
+        assert!(appendix.destination().is_none());
+    }
+}
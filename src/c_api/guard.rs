@@ -59,7 +59,7 @@ impl PdfiumGuard {
                 _guard: guard,
             }),
             Err(e) => Err(match e {
-                PdfiumError::LibraryError(msg) => PdfiumError::LibraryError(msg.to_string()),
+                PdfiumError::LibraryError(e) => PdfiumError::LibraryError(e.clone()),
                 _ => PdfiumError::Unknown,
             }),
         }
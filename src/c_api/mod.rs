@@ -25,10 +25,11 @@ pub mod pdfium_handle;
 pub mod pdfium_init;
 pub mod pdfium_private;
 pub mod pdfium_types;
+pub mod utf16;
 
 use std::{
     ffi::{CString, OsStr, OsString},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use libloading::{Library, Symbol};
@@ -39,7 +40,7 @@ use crate::{
         FPDF_RENDERER_TYPE_FPDF_RENDERERTYPE_AGG, FPDF_RENDERER_TYPE_FPDF_RENDERERTYPE_SKIA,
     },
     pdfium_types::FPDF_LIBRARY_CONFIG,
-    PdfiumError, PdfiumResult,
+    PdfiumError, PdfiumLibraryError, PdfiumResult,
 };
 
 impl Pdfium {
@@ -70,10 +71,12 @@ impl Pdfium {
     /// When a plain library filename is supplied, the locations in which the library is searched for
     /// are platform specific and cannot be adjusted in a portable manner.
     fn load_with_filename<P: AsRef<OsStr>>(filename: P) -> Result<Box<Pdfium>, PdfiumError> {
-        let lib = unsafe { Library::new(filename) };
+        let lib = unsafe { Library::new(filename.as_ref()) };
         let bindings = match lib {
             Ok(lib) => Pdfium::new(lib),
-            Err(e) => Err(PdfiumError::LibraryError(e.to_string())),
+            Err(e) => Err(PdfiumError::LibraryError(
+                PdfiumLibraryError::from_load_error(PathBuf::from(filename.as_ref()), &e),
+            )),
         };
         bindings.map(Box::new)
     }
@@ -121,7 +124,9 @@ pub(crate) fn dl<'a, T>(
             eprintln!("== Failed to get entry '{function}' from dynamic library");
             let e = e.to_string().replace("\n", "");
             eprintln!("== {e}");
-            Err(PdfiumError::LibraryError(e))
+            Err(PdfiumError::LibraryError(
+                PdfiumLibraryError::MissingSymbol(function.to_string()),
+            ))
         }
     }
 }
@@ -147,3 +152,34 @@ pub fn i32_to_result(i: i32) -> PdfiumResult<i32> {
         Ok(i)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+
+    #[test]
+    fn test_load_from_missing_directory() {
+        let directory = Path::new("resources/non_existing");
+        let Err(PdfiumError::LibraryError(PdfiumLibraryError::NotFound(path))) =
+            Pdfium::load_from_directory(directory)
+        else {
+            panic!("expected NotFound");
+        };
+        assert_eq!(path, directory.join(Pdfium::library_filename()));
+    }
+
+    #[test]
+    fn test_load_non_library_file() {
+        let directory = env::temp_dir().join(format!("pdfium-rs-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join(Pdfium::library_filename()), b"not a library").unwrap();
+        let result = Pdfium::load_from_directory(&directory);
+        fs::remove_dir_all(&directory).unwrap();
+        assert!(matches!(
+            result,
+            Err(PdfiumError::LibraryError(PdfiumLibraryError::LoadFailed(_)))
+        ));
+    }
+}
@@ -3226,10 +3226,11 @@ impl Pdfium {
     pub fn FPDFBookmark_GetFirstChild(
         &self,
         document: &PdfiumDocument,
-        bookmark: &PdfiumBookmark,
+        bookmark: Option<&PdfiumBookmark>,
     ) -> PdfiumResult<PdfiumBookmark> {
+        let bookmark = bookmark.map_or(std::ptr::null_mut(), |bookmark| bookmark.into());
         PdfiumBookmark::new_from_handle(unsafe {
-            (self.fn_FPDFBookmark_GetFirstChild)(document.into(), bookmark.into())
+            (self.fn_FPDFBookmark_GetFirstChild)(document.into(), bookmark)
         })
     }
 
@@ -3398,10 +3399,12 @@ impl Pdfium {
     /// Comments:
     ///       This function is a no-op when |hHandle| is null.
     /// ```
+    ///
+    /// This is a no-op: the form fill environment is exited when the last clone of the
+    /// [`PdfiumForm`] is dropped.
+    #[deprecated(note = "the form fill environment is exited when the PdfiumForm is dropped")]
     #[inline]
-    pub fn FPDFDOC_ExitFormFillEnvironment(&self, hHandle: &PdfiumForm) {
-        unsafe { (self.fn_FPDFDOC_ExitFormFillEnvironment)(hHandle.into()) }
-    }
+    pub fn FPDFDOC_ExitFormFillEnvironment(&self, _hHandle: &PdfiumForm) {}
 
     /// C documentation for FPDFDOC_InitFormFillEnvironment:
     ///
@@ -3424,9 +3427,11 @@ impl Pdfium {
         document: &PdfiumDocument,
         formInfo: &mut FPDF_FORMFILLINFO,
     ) -> PdfiumResult<PdfiumForm> {
-        PdfiumForm::new_from_handle(unsafe {
+        let mut form = PdfiumForm::new_from_handle(unsafe {
             (self.fn_FPDFDOC_InitFormFillEnvironment)(document.into(), formInfo)
-        })
+        })?;
+        form.set_owner(document.clone());
+        Ok(form)
     }
 
     /// C documentation for FPDFDest_GetDestPageIndex:
@@ -6422,9 +6427,11 @@ impl Pdfium {
         width: f64,
         height: f64,
     ) -> PdfiumResult<PdfiumPage> {
-        PdfiumPage::new_from_handle(unsafe {
+        let mut page = PdfiumPage::new_from_handle(unsafe {
             (self.fn_FPDFPage_New)(document.into(), page_index, width, height)
-        })
+        })?;
+        page.set_owner(document.clone());
+        Ok(page)
     }
 
     /// C documentation for FPDFPage_RemoveAnnot:
@@ -7065,7 +7072,7 @@ impl Pdfium {
     /// ```
     #[inline]
     pub fn FPDFTextObj_GetFont(&self, text: &PdfiumPageObject) -> PdfiumResult<PdfiumFont> {
-        PdfiumFont::new_from_handle(unsafe { (self.fn_FPDFTextObj_GetFont)(text.into()) })
+        PdfiumFont::new_from_borrowed_handle(unsafe { (self.fn_FPDFTextObj_GetFont)(text.into()) })
     }
 
     /// C documentation for FPDFTextObj_GetFontSize:
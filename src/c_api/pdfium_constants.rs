@@ -558,6 +558,24 @@ pub const FPDF_MATCHWHOLEWORD: i32 = 2;
 /// Find consecutive matches
 pub const FPDF_CONSECUTIVE: i32 = 4;
 
+/// Action type not supported by PDFium
+pub const PDFACTION_UNSUPPORTED: u32 = 0;
+
+/// Go to a destination within the current document
+pub const PDFACTION_GOTO: u32 = 1;
+
+/// Go to a destination within another document
+pub const PDFACTION_REMOTEGOTO: u32 = 2;
+
+/// Open a URI
+pub const PDFACTION_URI: u32 = 3;
+
+/// Launch an application or open a file
+pub const PDFACTION_LAUNCH: u32 = 4;
+
+/// Go to a destination within an embedded file
+pub const PDFACTION_EMBEDDEDGOTO: u32 = 5;
+
 /// Unknown text render mode
 pub const FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_UNKNOWN: FPDF_TEXT_RENDERMODE = -1;
 
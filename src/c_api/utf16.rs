@@ -0,0 +1,81 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+//! Decoding of the UTF-16 strings returned by PDFium
+
+use std::os::raw::c_ulong;
+
+/// Reads a UTF-16LE string using a PDFium function that takes a buffer and its length
+/// in bytes, and returns the required length in bytes including the terminating NUL.
+///
+/// Returns `None` if `f` returns 0, which PDFium uses to signal failure.
+pub(crate) fn read_utf16<F>(mut f: F) -> Option<String>
+where
+    F: FnMut(&mut Vec<u16>, c_ulong) -> c_ulong,
+{
+    let len = f(&mut Vec::new(), 0);
+    if len == 0 {
+        return None;
+    }
+    let mut buffer = vec![0u16; (len as usize).div_ceil(2)];
+    f(&mut buffer, len);
+    Some(utf16_to_string(&buffer))
+}
+
+/// Decodes UTF-16 code units up to the first NUL, replacing invalid data with
+/// [`char::REPLACEMENT_CHARACTER`].
+pub(crate) fn utf16_to_string(buffer: &[u16]) -> String {
+    let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..end])
+}
+
+/// Decodes UTF-16LE bytes up to the first NUL, replacing invalid data with
+/// [`char::REPLACEMENT_CHARACTER`].
+pub(crate) fn utf16le_to_string(buffer: &[u8]) -> String {
+    let utf16: Vec<u16> = buffer
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|&c| c != 0)
+        .collect();
+    String::from_utf16_lossy(&utf16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_utf16() {
+        let text: Vec<u16> = "Région\0".encode_utf16().collect();
+        let read = read_utf16(|buffer, buflen| {
+            if buflen as usize >= text.len() * 2 {
+                buffer.copy_from_slice(&text);
+            }
+            (text.len() * 2) as c_ulong
+        });
+        assert_eq!(read.as_deref(), Some("Région"));
+        assert_eq!(read_utf16(|_, _| 0), None);
+    }
+
+    #[test]
+    fn test_utf16le_to_string() {
+        let bytes: Vec<u8> = "Ab\0c".encode_utf16().flat_map(u16::to_le_bytes).collect();
+        assert_eq!(utf16le_to_string(&bytes), "Ab");
+        assert_eq!(utf16_to_string(&[0x41, 0xd800, 0]), "A\u{fffd}");
+    }
+}
@@ -17,12 +17,15 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::pdfium_types::FPDF_DWORD;
+use crate::{
+    error::{PdfiumError, PdfiumResult},
+    pdfium_types::FPDF_DWORD,
+};
 
 /// Rust interface to PDFium colors
 ///
 /// PDFium uses BGRA by default (32-bit color value with alpha channel).
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct PdfiumColor {
     red: u8,
     green: u8,
@@ -35,10 +38,12 @@ impl PdfiumColor {
     pub const WHITE: PdfiumColor = PdfiumColor::new(255, 255, 255, 255);
     /// Constant for the color black (#000000)
     pub const BLACK: PdfiumColor = PdfiumColor::new(0, 0, 0, 255);
+    /// Constant for fully transparent (#00000000)
+    pub const TRANSPARENT: PdfiumColor = PdfiumColor::new(0, 0, 0, 0);
 
     /// Constant for the color red (#ff0000)
     pub const RED: PdfiumColor = PdfiumColor::new(255, 0, 0, 255);
-    /// Constant for the color green (#00ff00)
+    /// Constant for the color green (#008000), as in CSS
     pub const GREEN: PdfiumColor = PdfiumColor::new(0, 128, 0, 255);
     /// Constant for the color blue (#0000ff)
     pub const BLUE: PdfiumColor = PdfiumColor::new(0, 0, 255, 255);
@@ -49,6 +54,15 @@ impl PdfiumColor {
     pub const MAGENTA: PdfiumColor = PdfiumColor::new(255, 0, 255, 255);
     /// Constant for the color cyan (#00ffff)
     pub const CYAN: PdfiumColor = PdfiumColor::new(0, 255, 255, 255);
+    /// Constant for the color orange (#ffa500)
+    pub const ORANGE: PdfiumColor = PdfiumColor::new(255, 165, 0, 255);
+
+    /// Constant for the color gray (#808080)
+    pub const GRAY: PdfiumColor = PdfiumColor::new(128, 128, 128, 255);
+    /// Constant for the color light gray (#d3d3d3)
+    pub const LIGHT_GRAY: PdfiumColor = PdfiumColor::new(211, 211, 211, 255);
+    /// Constant for the color dark gray (#a9a9a9), which in CSS is lighter than gray
+    pub const DARK_GRAY: PdfiumColor = PdfiumColor::new(169, 169, 169, 255);
 
     /// Constructs a new [`PdfiumColor`] instance from the given components.
     pub const fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
@@ -59,15 +73,337 @@ impl PdfiumColor {
             alpha,
         }
     }
+
+    /// Returns the [`PdfiumColor`] for a CSS color keyword, ignoring case.
+    ///
+    /// Covers the basic CSS colors (e.g. `red`, `navy`, `silver`), `orange`, `cyan`,
+    /// `magenta`, `lightgray`, `darkgray` (also spelled with `grey`) and `transparent`.
+    pub fn from_css_name(name: &str) -> Option<PdfiumColor> {
+        let color = match name.to_ascii_lowercase().as_str() {
+            "black" => Self::BLACK,
+            "silver" => Self::new(192, 192, 192, 255),
+            "gray" | "grey" => Self::GRAY,
+            "white" => Self::WHITE,
+            "maroon" => Self::new(128, 0, 0, 255),
+            "red" => Self::RED,
+            "purple" => Self::new(128, 0, 128, 255),
+            "fuchsia" | "magenta" => Self::MAGENTA,
+            "green" => Self::GREEN,
+            "lime" => Self::new(0, 255, 0, 255),
+            "olive" => Self::new(128, 128, 0, 255),
+            "yellow" => Self::YELLOW,
+            "navy" => Self::new(0, 0, 128, 255),
+            "blue" => Self::BLUE,
+            "teal" => Self::new(0, 128, 128, 255),
+            "aqua" | "cyan" => Self::CYAN,
+            "orange" => Self::ORANGE,
+            "lightgray" | "lightgrey" => Self::LIGHT_GRAY,
+            "darkgray" | "darkgrey" => Self::DARK_GRAY,
+            "transparent" => Self::TRANSPARENT,
+            _ => return None,
+        };
+        Some(color)
+    }
+
+    /// Returns the red component of this [`PdfiumColor`].
+    pub fn red(&self) -> u8 {
+        self.red
+    }
+
+    /// Returns the green component of this [`PdfiumColor`].
+    pub fn green(&self) -> u8 {
+        self.green
+    }
+
+    /// Returns the blue component of this [`PdfiumColor`].
+    pub fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    /// Returns the alpha component of this [`PdfiumColor`].
+    pub fn alpha(&self) -> u8 {
+        self.alpha
+    }
+
+    /// Linearly interpolates between this [`PdfiumColor`] and `other`.
+    ///
+    /// `t` is clamped to `0.0..=1.0`, where `0.0` returns this color and `1.0` returns
+    /// `other`. Interpolation is done in linear light on premultiplied alpha, so the
+    /// midpoint between two colors is not too dark and fully transparent colors do not
+    /// bleed their (invisible) color into the result.
+    pub fn lerp(&self, other: &PdfiumColor, t: f32) -> PdfiumColor {
+        let t = t.clamp(0.0, 1.0);
+        if t == 0.0 {
+            return *self;
+        }
+        if t == 1.0 {
+            return *other;
+        }
+
+        fn to_srgb(c: f32) -> u8 {
+            let c = if c <= 0.0031308 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            };
+            (c.clamp(0.0, 1.0) * 255.0).round() as u8
+        }
+
+        let alpha_a = self.alpha as f32 / 255.0;
+        let alpha_b = other.alpha as f32 / 255.0;
+        let alpha = alpha_a + (alpha_b - alpha_a) * t;
+        let channel = |a: u8, b: u8| {
+            if alpha == 0.0 {
+                return 0;
+            }
+            let a = srgb_to_linear(a) * alpha_a;
+            let b = srgb_to_linear(b) * alpha_b;
+            to_srgb((a + (b - a) * t) / alpha)
+        };
+        PdfiumColor::new(
+            channel(self.red, other.red),
+            channel(self.green, other.green),
+            channel(self.blue, other.blue),
+            (alpha * 255.0).round() as u8,
+        )
+    }
+
+    /// Returns the relative luminance of this [`PdfiumColor`] as defined by WCAG 2, from `0.0`
+    /// (black) to `1.0` (white).
+    ///
+    /// The alpha component is ignored.
+    pub fn relative_luminance(&self) -> f32 {
+        0.2126 * srgb_to_linear(self.red)
+            + 0.7152 * srgb_to_linear(self.green)
+            + 0.0722 * srgb_to_linear(self.blue)
+    }
+
+    /// Returns the WCAG 2 contrast ratio between this [`PdfiumColor`] and `other`, from `1.0`
+    /// (no contrast) to `21.0` (black and white).
+    ///
+    /// The ratio is symmetric, it does not matter which color is the text color. The alpha
+    /// components are ignored, so blend semi-transparent colors with their background first.
+    pub fn contrast_ratio(&self, other: &PdfiumColor) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns `true` if text in this [`PdfiumColor`] on `background` meets WCAG 2 level AA.
+    ///
+    /// Level AA requires a contrast ratio of at least 4.5, or 3.0 for `large_text` (at least
+    /// 18 point, or 14 point bold).
+    pub fn meets_wcag_aa(&self, background: &PdfiumColor, large_text: bool) -> bool {
+        let minimum = if large_text { 3.0 } else { 4.5 };
+        self.contrast_ratio(background) >= minimum
+    }
+
+    /// Parses a [`PdfiumColor`] from a hex string.
+    ///
+    /// Accepted forms are `#RGB`, `#RRGGBB` and `#RRGGBBAA`. The leading `#` is optional.
+    /// Colors without an alpha component are fully opaque.
+    ///
+    /// Returns [`PdfiumError::ColorError`] if the string is not a valid hex color.
+    pub fn from_hex(s: &str) -> PdfiumResult<Self> {
+        let hex = s.strip_prefix('#').unwrap_or(s);
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(PdfiumError::ColorError);
+        }
+        let component = |i: usize, len: usize| {
+            u8::from_str_radix(&hex[i..i + len], 16).map_err(|_| PdfiumError::ColorError)
+        };
+        match hex.len() {
+            3 => Ok(Self::new(
+                component(0, 1)? * 17,
+                component(1, 1)? * 17,
+                component(2, 1)? * 17,
+                255,
+            )),
+            6 => Ok(Self::new(
+                component(0, 2)?,
+                component(2, 2)?,
+                component(4, 2)?,
+                255,
+            )),
+            8 => Ok(Self::new(
+                component(0, 2)?,
+                component(2, 2)?,
+                component(4, 2)?,
+                component(6, 2)?,
+            )),
+            _ => Err(PdfiumError::ColorError),
+        }
+    }
+
+    /// Returns this [`PdfiumColor`] as a 32-bit `0xAARRGGBB` value.
+    ///
+    /// Alpha is in the high byte, followed by red, green and blue in the low byte. This is
+    /// the 8888 ARGB layout expected by `FPDFBitmap_FillRect`, so opaque red is
+    /// `0xFFFF0000`.
+    pub const fn to_argb_u32(&self) -> u32 {
+        (self.alpha as u32) << 24
+            | (self.red as u32) << 16
+            | (self.green as u32) << 8
+            | self.blue as u32
+    }
+
+    /// Creates a [`PdfiumColor`] from a 32-bit `0xAARRGGBB` value.
+    ///
+    /// This is the inverse of [`PdfiumColor::to_argb_u32`].
+    pub const fn from_argb_u32(argb: u32) -> Self {
+        Self::new(
+            (argb >> 16) as u8,
+            (argb >> 8) as u8,
+            argb as u8,
+            (argb >> 24) as u8,
+        )
+    }
+
+    /// Formats this [`PdfiumColor`] as a `#RRGGBBAA` hex string.
+    pub fn to_hex(&self) -> String {
+        format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            self.red, self.green, self.blue, self.alpha
+        )
+    }
 }
 
 impl From<&PdfiumColor> for FPDF_DWORD {
-    /// Converts the [`PdfiumColor`] to the BGRA value
+    /// Converts the [`PdfiumColor`] to the ARGB value, see [`PdfiumColor::to_argb_u32`]
     fn from(value: &PdfiumColor) -> Self {
-        let red = value.red as FPDF_DWORD;
-        let green = value.green as FPDF_DWORD;
-        let blue = value.blue as FPDF_DWORD;
-        let alpha = value.alpha as FPDF_DWORD;
-        (alpha << 24) | (red << 16) | (green << 8) | blue
+        value.to_argb_u32() as FPDF_DWORD
+    }
+}
+
+/// Converts an sRGB channel value to linear light (`0.0` - `1.0`).
+fn srgb_to_linear(c: u8) -> f32 {
+    let c = c as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_from_hex() {
+        assert_eq!(
+            PdfiumColor::from_hex("#f80").unwrap(),
+            PdfiumColor::new(255, 136, 0, 255)
+        );
+        assert_eq!(
+            PdfiumColor::from_hex("#00FF7f").unwrap(),
+            PdfiumColor::new(0, 255, 127, 255)
+        );
+        assert_eq!(
+            PdfiumColor::from_hex("#12345678").unwrap(),
+            PdfiumColor::new(0x12, 0x34, 0x56, 0x78)
+        );
+        assert_eq!(PdfiumColor::from_hex("ffffff").unwrap(), PdfiumColor::WHITE);
+
+        assert!(PdfiumColor::from_hex("#12345").is_err());
+        assert!(PdfiumColor::from_hex("#gg0000").is_err());
+        assert!(PdfiumColor::from_hex("#+1+2+3").is_err());
+        assert!(PdfiumColor::from_hex("#ééé").is_err());
+        assert!(PdfiumColor::from_hex("").is_err());
+    }
+
+    #[test]
+    fn test_color_hex_round_trip() {
+        assert_eq!(PdfiumColor::BLACK.to_hex(), "#000000FF");
+        assert_eq!(PdfiumColor::GREEN.to_hex(), "#008000FF");
+        for color in [
+            PdfiumColor::WHITE,
+            PdfiumColor::TRANSPARENT,
+            PdfiumColor::new(1, 2, 3, 4),
+        ] {
+            assert_eq!(PdfiumColor::from_hex(&color.to_hex()).unwrap(), color);
+        }
+    }
+
+    #[test]
+    fn test_color_argb() {
+        assert_eq!(PdfiumColor::RED.to_argb_u32(), 0xFFFF0000);
+        assert_eq!(PdfiumColor::GREEN.to_argb_u32(), 0xFF008000);
+        assert_eq!(PdfiumColor::TRANSPARENT.to_argb_u32(), 0x00000000);
+        assert_eq!(
+            PdfiumColor::from_argb_u32(0x80112233),
+            PdfiumColor::new(0x11, 0x22, 0x33, 0x80)
+        );
+        for color in [
+            PdfiumColor::WHITE,
+            PdfiumColor::BLUE,
+            PdfiumColor::new(1, 2, 3, 4),
+        ] {
+            assert_eq!(PdfiumColor::from_argb_u32(color.to_argb_u32()), color);
+            assert_eq!(FPDF_DWORD::from(&color), color.to_argb_u32() as FPDF_DWORD);
+        }
+    }
+
+    #[test]
+    fn test_color_named() {
+        assert_eq!(PdfiumColor::ORANGE.to_argb_u32(), 0xFFFFA500);
+        assert_eq!(PdfiumColor::GRAY.to_argb_u32(), 0xFF808080);
+        assert_eq!(PdfiumColor::LIGHT_GRAY.to_argb_u32(), 0xFFD3D3D3);
+        assert_eq!(PdfiumColor::CYAN.to_argb_u32(), 0xFF00FFFF);
+
+        assert_eq!(PdfiumColor::from_css_name("red"), Some(PdfiumColor::RED));
+        assert_eq!(PdfiumColor::from_css_name("Grey"), Some(PdfiumColor::GRAY));
+        assert_eq!(PdfiumColor::from_css_name("AQUA"), Some(PdfiumColor::CYAN));
+        assert_eq!(
+            PdfiumColor::from_css_name("navy"),
+            Some(PdfiumColor::new(0, 0, 128, 255))
+        );
+        assert_eq!(
+            PdfiumColor::from_css_name("transparent"),
+            Some(PdfiumColor::TRANSPARENT)
+        );
+        assert_eq!(PdfiumColor::from_css_name("rebeccapurple"), None);
+        assert_eq!(PdfiumColor::from_css_name(""), None);
+    }
+
+    #[test]
+    fn test_color_lerp() {
+        let red = PdfiumColor::new(255, 0, 0, 255);
+        let blue = PdfiumColor::new(0, 0, 255, 255);
+        assert_eq!(red.lerp(&blue, 0.0), red);
+        assert_eq!(red.lerp(&blue, 1.0), blue);
+        assert_eq!(red.lerp(&blue, -1.0), red);
+        assert_eq!(red.lerp(&blue, 2.0), blue);
+
+        // Linear light midpoint of 1.0 and 0.0 is 0.5, which is 188 in sRGB
+        assert_eq!(red.lerp(&blue, 0.5), PdfiumColor::new(188, 0, 188, 255));
+        assert_eq!(
+            PdfiumColor::BLACK.lerp(&PdfiumColor::WHITE, 0.5),
+            PdfiumColor::new(188, 188, 188, 255)
+        );
+
+        // The color of a fully transparent end point does not bleed into the result
+        let transparent_black = PdfiumColor::new(0, 0, 0, 0);
+        assert_eq!(
+            PdfiumColor::WHITE.lerp(&transparent_black, 0.5),
+            PdfiumColor::new(255, 255, 255, 128)
+        );
+    }
+
+    #[test]
+    fn test_color_contrast_ratio() {
+        let (black, white) = (PdfiumColor::BLACK, PdfiumColor::WHITE);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.01);
+        assert_eq!(black.contrast_ratio(&white), white.contrast_ratio(&black));
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+        assert!(black.meets_wcag_aa(&white, false));
+
+        // #777777 on white is the classic near miss at about 4.48
+        let gray = PdfiumColor::new(0x77, 0x77, 0x77, 255);
+        assert!((gray.contrast_ratio(&white) - 4.48).abs() < 0.01);
+        assert!(!gray.meets_wcag_aa(&white, false));
+        assert!(gray.meets_wcag_aa(&white, true));
+
+        let light_gray = PdfiumColor::LIGHT_GRAY;
+        assert!(!light_gray.meets_wcag_aa(&white, true));
     }
 }
@@ -0,0 +1,91 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::{Duration, Instant};
+
+use crate::error::{PdfiumError, PdfiumResult};
+
+/// Point in time after which a long-running operation should give up
+///
+/// Used by [`PdfiumPage::render_with_timeout`](crate::PdfiumPage::render_with_timeout), which
+/// returns [`PdfiumError::Timeout`] once the deadline has expired.
+#[derive(Debug, Copy, Clone)]
+pub struct PdfiumDeadline {
+    start: Instant,
+    timeout: Duration,
+}
+
+impl PdfiumDeadline {
+    /// Creates a [`PdfiumDeadline`] that expires `timeout` from now.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            start: Instant::now(),
+            timeout,
+        }
+    }
+
+    /// Returns the timeout this [`PdfiumDeadline`] was created with.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns `true` if the deadline has passed.
+    pub fn expired(&self) -> bool {
+        self.start.elapsed() >= self.timeout
+    }
+
+    /// Returns the time left until the deadline, or [`Duration::ZERO`] if it has passed.
+    pub fn remaining(&self) -> Duration {
+        self.timeout.saturating_sub(self.start.elapsed())
+    }
+
+    /// Returns [`PdfiumError::Timeout`] if the deadline has passed.
+    pub fn check(&self) -> PdfiumResult<()> {
+        if self.expired() {
+            Err(PdfiumError::Timeout)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::sleep;
+
+    use super::*;
+
+    #[test]
+    fn test_deadline_expiry() {
+        let deadline = PdfiumDeadline::new(Duration::ZERO);
+        assert!(deadline.expired());
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+        assert!(matches!(deadline.check(), Err(PdfiumError::Timeout)));
+
+        let deadline = PdfiumDeadline::new(Duration::from_secs(60));
+        assert!(!deadline.expired());
+        assert!(deadline.remaining() > Duration::from_secs(59));
+        assert!(deadline.check().is_ok());
+
+        let deadline = PdfiumDeadline::new(Duration::from_millis(20));
+        sleep(Duration::from_millis(30));
+        assert!(deadline.expired());
+        assert_eq!(deadline.timeout(), Duration::from_millis(20));
+    }
+}
@@ -0,0 +1,179 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{PdfiumDocument, PdfiumResult};
+
+/// Text changes of a single page, as returned by [`PdfiumDocument::text_diff`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfiumPageTextDiff {
+    /// Zero-based index of the page
+    pub page_index: i32,
+
+    /// Lines only present in the other document, in page order
+    pub added: Vec<String>,
+
+    /// Lines only present in this document, in page order
+    pub removed: Vec<String>,
+}
+
+impl PdfiumDocument {
+    /// Compares the text of each page of this [`PdfiumDocument`] with the same page of
+    /// `other`, returning the pages whose text changed.
+    ///
+    /// The text of each page is split into lines, and the lines are compared with a longest
+    /// common subsequence diff. Leading and trailing white space and empty lines are ignored.
+    /// Pages are compared by index, so inserting a page reports all following pages as
+    /// changed. Pages only present in `other` have all their lines added, pages only present
+    /// in this document have all their lines removed.
+    pub fn text_diff(&self, other: &PdfiumDocument) -> PdfiumResult<Vec<PdfiumPageTextDiff>> {
+        let page_text = |document: &PdfiumDocument, index: i32| -> PdfiumResult<String> {
+            if index < document.page_count() {
+                Ok(document.page(index)?.text()?.full())
+            } else {
+                Ok(String::new())
+            }
+        };
+        let mut diffs = Vec::new();
+        for page_index in 0..self.page_count().max(other.page_count()) {
+            let old = page_text(self, page_index)?;
+            let new = page_text(other, page_index)?;
+            if old == new {
+                continue;
+            }
+            let (added, removed) = diff_lines(&lines(&old), &lines(&new));
+            if !added.is_empty() || !removed.is_empty() {
+                diffs.push(PdfiumPageTextDiff {
+                    page_index,
+                    added,
+                    removed,
+                });
+            }
+        }
+        Ok(diffs)
+    }
+}
+
+/// Returns the trimmed, non-empty lines of `text`.
+fn lines(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Returns the lines (added, removed) going from `old` to `new`.
+fn diff_lines(old: &[&str], new: &[&str]) -> (Vec<String>, Vec<String>) {
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut added, mut removed) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            removed.push(old[i].to_string());
+            i += 1;
+        } else {
+            added.push(new[j].to_string());
+            j += 1;
+        }
+    }
+    removed.extend(old[i..].iter().map(|line| line.to_string()));
+    added.extend(new[j..].iter().map(|line| line.to_string()));
+    (added, removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, io::Cursor};
+
+    use crate::*;
+
+    fn document(pages: &[&[&str]]) -> PdfiumDocument {
+        let document = PdfiumDocument::new().unwrap();
+        let font = CString::new("Helvetica").unwrap();
+        for (index, lines) in pages.iter().enumerate() {
+            let page = lib()
+                .FPDFPage_New(&document, index as i32, 200.0, 200.0)
+                .unwrap();
+            for (line, content) in lines.iter().enumerate() {
+                let text = lib()
+                    .FPDFPageObj_NewTextObj(&document, &font, 12.0)
+                    .unwrap();
+                lib().FPDFText_SetText(&text, content).unwrap();
+                text.transform(1.0, 0.0, 0.0, 1.0, 20.0, 150.0 - 20.0 * line as f64);
+                page.insert_object_at(text, line as i32).unwrap();
+            }
+        }
+        let bytes = document.save_to_bytes(None).unwrap();
+        PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap()
+    }
+
+    #[test]
+    fn test_text_diff() {
+        let original = document(&[
+            &["Apple pie", "Serves four"],
+            &["Banana bread", "Bake for an hour"],
+        ]);
+        let edited = document(&[
+            &["Apple pie", "Serves four"],
+            &["Banana cake", "Bake for an hour"],
+            &["Crumble"],
+        ]);
+        assert!(original.text_diff(&original).unwrap().is_empty());
+
+        let diffs = original.text_diff(&edited).unwrap();
+        assert_eq!(
+            diffs,
+            [
+                PdfiumPageTextDiff {
+                    page_index: 1,
+                    added: vec!["Banana cake".to_string()],
+                    removed: vec!["Banana bread".to_string()],
+                },
+                PdfiumPageTextDiff {
+                    page_index: 2,
+                    added: vec!["Crumble".to_string()],
+                    removed: vec![],
+                },
+            ]
+        );
+
+        let diffs = edited.text_diff(&original).unwrap();
+        assert_eq!(diffs[1].removed, ["Crumble"]);
+    }
+
+    #[test]
+    fn test_diff_lines() {
+        let (added, removed) = super::diff_lines(&["a", "b", "c", "d"], &["a", "c", "x", "d", "e"]);
+        assert_eq!(added, ["x", "e"]);
+        assert_eq!(removed, ["b"]);
+    }
+}
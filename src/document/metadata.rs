@@ -0,0 +1,130 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{ffi::CString, os::raw::c_ulong};
+
+use crate::{c_api::utf16::utf16le_to_string, lib, PdfiumDocument};
+
+/// Values of the document information dictionary, as returned by [`PdfiumDocument::metadata`]
+///
+/// Each getter reads its value from the document when called. Getters return `None` if the
+/// value is absent or empty. Dates are returned as stored, in the PDF date format
+/// `D:YYYYMMDDHHmmSSOHH'mm'`.
+#[derive(Debug, Clone)]
+pub struct PdfiumDocumentMetadata {
+    document: PdfiumDocument,
+}
+
+impl PdfiumDocumentMetadata {
+    /// Returns the title of the document.
+    pub fn title(&self) -> Option<String> {
+        self.get("Title")
+    }
+
+    /// Returns the name of the person who created the document.
+    pub fn author(&self) -> Option<String> {
+        self.get("Author")
+    }
+
+    /// Returns the subject of the document.
+    pub fn subject(&self) -> Option<String> {
+        self.get("Subject")
+    }
+
+    /// Returns the keywords associated with the document.
+    pub fn keywords(&self) -> Option<String> {
+        self.get("Keywords")
+    }
+
+    /// Returns the name of the application that created the original document.
+    pub fn creator(&self) -> Option<String> {
+        self.get("Creator")
+    }
+
+    /// Returns the name of the application that converted the document to PDF.
+    pub fn producer(&self) -> Option<String> {
+        self.get("Producer")
+    }
+
+    /// Returns the date and time the document was created.
+    pub fn creation_date(&self) -> Option<String> {
+        self.get("CreationDate")
+    }
+
+    /// Returns the date and time the document was most recently modified.
+    pub fn mod_date(&self) -> Option<String> {
+        self.get("ModDate")
+    }
+
+    /// Returns the value of `tag` in the document information dictionary.
+    fn get(&self, tag: &str) -> Option<String> {
+        let tag = CString::new(tag).ok()?;
+        let lib = lib();
+        // The length includes the two terminating zero bytes, so an absent value has length 2
+        let len = lib.FPDF_GetMetaText(&self.document, &tag, None, 0);
+        if len <= 2 {
+            return None;
+        }
+        let mut buffer = vec![0u8; len as usize];
+        lib.FPDF_GetMetaText(&self.document, &tag, Some(&mut buffer), len as c_ulong);
+        Some(utf16le_to_string(&buffer))
+    }
+}
+
+impl PdfiumDocument {
+    /// Returns the [`PdfiumDocumentMetadata`] of this [`PdfiumDocument`], giving access to the
+    /// title, author, dates and other values of the document information dictionary.
+    ///
+    /// For linearized documents loaded progressively with [`crate::PdfiumAvailability`], the
+    /// metadata may not be available before the whole document has been loaded.
+    pub fn metadata(&self) -> PdfiumDocumentMetadata {
+        PdfiumDocumentMetadata {
+            document: self.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_metadata() {
+        let document =
+            PdfiumDocument::new_from_path("resources/pg1342-images-3.pdf", None).unwrap();
+        let metadata = document.metadata();
+        assert_eq!(metadata.title().as_deref(), Some("Pride and Prejudice"));
+        assert_eq!(metadata.author().as_deref(), Some("Jane Austen"));
+        assert_eq!(metadata.creator().as_deref(), Some("calibre 8.6.0"));
+        assert_eq!(metadata.producer().as_deref(), Some("calibre 8.6.0"));
+        assert_eq!(
+            metadata.creation_date().as_deref(),
+            Some("D:20250728173920+00'00'")
+        );
+        assert!(metadata
+            .keywords()
+            .unwrap()
+            .starts_with("England -- Fiction"));
+        assert_eq!(metadata.subject(), None);
+
+        let metadata = PdfiumDocument::new().unwrap().metadata();
+        assert_eq!(metadata.title(), None);
+        assert_eq!(metadata.mod_date(), None);
+    }
+}
@@ -17,35 +17,70 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod diff;
+pub mod metadata;
 pub mod reader;
+pub mod stats;
+#[cfg(feature = "tiff")]
+mod tiff;
+pub mod validation;
 pub mod writer;
 
 use std::{
+    cell::{Cell, RefCell},
     ffi::CString,
     fmt::Debug,
     fs::File,
     io::{Cursor, Read, Seek, Write},
+    ops::ControlFlow,
     path::Path,
     rc::Rc,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
 };
 
+use image::{codecs::jpeg::JpegEncoder, imageops::FilterType, DynamicImage};
+
 use crate::{
     document::{reader::PdfiumReader, writer::PdfiumWriter},
     error::{PdfiumError, PdfiumResult},
     lib,
-    page::{pages::PdfiumPages, PdfiumPage},
-    pdfium_types::{DocumentHandle, Handle, FPDF_DOCUMENT},
-    try_lib,
+    page::{
+        boundaries::PdfiumPageBoundaries,
+        object::{ObjectType, PdfiumPageObject},
+        pages::PdfiumPages,
+        PdfiumPage,
+    },
+    pdfium_constants::{
+        FPDF_COLORSPACE_UNKNOWN, FPDF_FILEIDTYPE_FILEIDTYPE_PERMANENT, FPDF_INCREMENTAL,
+        FPDF_OBJECT_DICTIONARY, FPDF_REMOVE_SECURITY,
+    },
+    pdfium_types::{
+        DocumentHandle, Handle, PageHandle, FPDF_DOCUMENT, FPDF_DWORD, FPDF_FILEIDTYPE,
+        FPDF_IMAGEOBJ_METADATA, FPDF_PAGE, FS_MATRIX, FS_SIZEF,
+    },
+    try_lib, PdfiumAnnotationRef, PdfiumAvailability, PdfiumBitmap, PdfiumBookmark,
+    PdfiumBookmarks, PdfiumMatrix, PdfiumPageSelection, PdfiumRect, PdfiumRenderConfig,
+    PdfiumSearchFlags, PdfiumSearchHit, PdfiumSignature,
 };
 
 /// Rust interface to FPDF_DOCUMENT
 #[derive(Clone)]
 pub struct PdfiumDocument {
+    // Declared before `handle` so edited pages are closed before the document itself.
+    dirty_pages: Rc<RefCell<Vec<PageHandle>>>,
+    auto_generate_content: Rc<Cell<bool>>,
+    page_load_hook: Rc<RefCell<Option<PageLoadHook>>>,
     handle: DocumentHandle,
+    availability: Option<PdfiumAvailability>,
     #[allow(clippy::redundant_allocation)]
     _reader: Option<Rc<Box<PdfiumReader>>>,
 }
 
+/// Callback set with [`PdfiumDocument::on_page_load`]
+type PageLoadHook = Box<dyn FnMut(i32, Duration)>;
+
 impl Debug for PdfiumDocument {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PdfiumDocument")
@@ -60,28 +95,98 @@ impl PdfiumDocument {
         reader: Option<Box<PdfiumReader>>,
     ) -> PdfiumResult<Self> {
         if handle.is_null() {
-            Err(match try_lib()?.FPDF_GetLastError() as i32 {
-                crate::pdfium_constants::FPDF_ERR_FILE => PdfiumError::FileError,
-                crate::pdfium_constants::FPDF_ERR_FORMAT => PdfiumError::FormatError,
-                crate::pdfium_constants::FPDF_ERR_PASSWORD => PdfiumError::PasswordError,
-                crate::pdfium_constants::FPDF_ERR_SECURITY => PdfiumError::SecurityError,
-                crate::pdfium_constants::FPDF_ERR_PAGE => PdfiumError::PageError,
-                _ => PdfiumError::Unknown,
-            })
+            Err(last_error()?)
         } else {
             Ok(Self {
+                dirty_pages: Rc::default(),
+                auto_generate_content: Rc::new(Cell::new(true)),
+                page_load_hook: Rc::default(),
                 handle: Handle::new(handle, Some(close_document)),
+                availability: None,
                 _reader: reader.map(Rc::new),
             })
         }
     }
 
+    pub(crate) fn new_from_availability(
+        handle: FPDF_DOCUMENT,
+        availability: PdfiumAvailability,
+    ) -> PdfiumResult<Self> {
+        if handle.is_null() {
+            Err(last_error()?)
+        } else {
+            Ok(Self {
+                dirty_pages: Rc::default(),
+                auto_generate_content: Rc::new(Cell::new(true)),
+                page_load_hook: Rc::default(),
+                handle: Handle::new(handle, Some(close_document)),
+                availability: Some(availability),
+                _reader: None,
+            })
+        }
+    }
+
     /// Creates a new empty [`PdfiumDocument`]
     pub fn new() -> PdfiumResult<Self> {
         let handle = try_lib()?.FPDF_CreateNewDocument();
         Self::new_from_handle(handle, None)
     }
 
+    /// Creates a new [`PdfiumDocument`] with one page per image in `images`, e.g. to assemble
+    /// scanned pages into a PDF.
+    ///
+    /// With `page_size` (width, height in points) every page gets that size and its image is
+    /// scaled to fit the page, keeping its aspect ratio and centered. Without it, each page
+    /// gets the size of its image at 72 DPI, so one pixel becomes one point.
+    ///
+    /// Images are stored losslessly, so scans may be large. See
+    /// [`PdfiumDocument::downsample_images`] to reduce their size.
+    pub fn from_images(
+        images: &[DynamicImage],
+        page_size: Option<(f32, f32)>,
+    ) -> PdfiumResult<PdfiumDocument> {
+        if let Some((width, height)) = page_size {
+            if !(width > 0.0 && height > 0.0 && width.is_finite() && height.is_finite()) {
+                return Err(PdfiumError::InvalidConfiguration(
+                    "Page size must be positive".to_string(),
+                ));
+            }
+        }
+        let document = PdfiumDocument::new()?;
+        for (index, image) in images.iter().enumerate() {
+            let (image_width, image_height) = (image.width() as f32, image.height() as f32);
+            if image_width == 0.0 || image_height == 0.0 {
+                return Err(PdfiumError::ImageError);
+            }
+            let (page_width, page_height) = page_size.unwrap_or((image_width, image_height));
+            let scale = (page_width / image_width).min(page_height / image_height);
+            let (width, height) = (image_width * scale, image_height * scale);
+
+            let page = lib().FPDFPage_New(
+                &document,
+                index as i32,
+                page_width as f64,
+                page_height as f64,
+            )?;
+
+            let bitmap = PdfiumBitmap::from_image(image)?;
+            let object = lib().FPDFPageObj_NewImageObj(&document)?;
+            let mut pages: FPDF_PAGE = (&page).into();
+            lib().FPDFImageObj_SetBitmap(&mut pages, 1, (&object).into(), (&bitmap).into())?;
+            object.set_matrix(&FS_MATRIX {
+                a: width,
+                b: 0.0,
+                c: 0.0,
+                d: height,
+                e: (page_width - width) / 2.0,
+                f: (page_height - height) / 2.0,
+            })?;
+            page.insert_object_at(object, 0)?;
+            page.generate_content()?;
+        }
+        Ok(document)
+    }
+
     /// Load a [`PdfiumDocument`] from a `Path`
     pub fn new_from_path<P: AsRef<Path>>(path: P, password: Option<&str>) -> PdfiumResult<Self> {
         let reader = File::open(path)?;
@@ -89,6 +194,8 @@ impl PdfiumDocument {
     }
 
     /// Load a [`PdfiumDocument`] using a reader implementing `Read` and `Seek`
+    ///
+    /// Returns [`PdfiumError::IoError`] if the reader fails while the document is loaded.
     pub fn new_from_reader<R: Read + Seek + 'static>(
         reader: R,
         password: Option<&str>,
@@ -97,7 +204,196 @@ impl PdfiumDocument {
         let mut reader = PdfiumReader::new(reader);
         let password = CString::new(password.unwrap_or("")).unwrap();
         let handle = lib.FPDF_LoadCustomDocument(&mut reader, &password);
-        Self::new_from_handle(handle, Some(reader))
+        // A failing reader would otherwise surface as a confusing format error
+        let error = reader.take_error();
+        let document = Self::new_from_handle(handle, Some(reader));
+        match error {
+            Some(err) => Err(err.into()),
+            None => document,
+        }
+    }
+
+    /// Load a [`PdfiumDocument`] from a reader of which only the first `available` bytes
+    /// have been downloaded.
+    ///
+    /// This is intended for linearized ("fast web view") PDFs, where the first page can be
+    /// loaded and rendered before the rest of the document is available. Use
+    /// [`PdfiumDocument::first_available_page`] and [`PdfiumDocument::is_page_available`]
+    /// to find out which pages can be loaded, and [`PdfiumAvailability::set_available`]
+    /// (through [`PdfiumDocument::availability`]) as more data arrives.
+    ///
+    /// Returns [`PdfiumError::NotFound`] if not enough data is available to load the document.
+    pub fn new_from_file_range<R: Read + Seek + 'static>(
+        reader: R,
+        available: u64,
+        password: Option<&str>,
+    ) -> PdfiumResult<Self> {
+        PdfiumAvailability::new(reader, available)?.document(password)
+    }
+
+    /// Returns an independent deep copy of this [`PdfiumDocument`].
+    ///
+    /// Cloning a [`PdfiumDocument`] only clones the handle, so all clones share the same
+    /// underlying document and see each other's edits. This method instead saves the
+    /// document to memory and loads the result into a fresh [`PdfiumDocument`], so the copy
+    /// can be edited while keeping the original intact.
+    ///
+    /// Pending edits are included in the copy, see [`PdfiumDocument::save_to_bytes`]. The copy
+    /// of an encrypted document is not encrypted, so it can be loaded without a password.
+    pub fn duplicate(&self) -> PdfiumResult<PdfiumDocument> {
+        let cursor = Cursor::new(Vec::new());
+        let cursor = self.save_with_flags(cursor, None, FPDF_REMOVE_SECURITY as FPDF_DWORD)?;
+        PdfiumDocument::new_from_reader(Cursor::new(cursor.into_inner()), None)
+    }
+
+    /// Downsamples embedded images that are displayed at more than `max_dpi`.
+    ///
+    /// Every image object whose effective resolution on its page exceeds `max_dpi` is resized
+    /// to `max_dpi`, re-encoded as JPEG with `jpeg_quality` (1 - 100) and stored inline,
+    /// replacing the original image data. Returns the number of images modified.
+    ///
+    /// This is lossy: the original image data is discarded. Images with transparency, stencil
+    /// masks (/ImageMask), images drawn through a /Mask and images in an unsupported pixel
+    /// format are left untouched, as are images inside form XObjects.
+    pub fn downsample_images(&self, max_dpi: f32, jpeg_quality: u8) -> PdfiumResult<usize> {
+        if max_dpi <= 0.0 || !max_dpi.is_finite() {
+            return Err(PdfiumError::InvalidConfiguration(
+                "DPI must be positive".to_string(),
+            ));
+        }
+        let jpeg_quality = jpeg_quality.clamp(1, 100);
+        let mut modified = 0;
+        for page in self.pages() {
+            let page = page?;
+            let mut page_modified = false;
+            for object in page.objects() {
+                let object = object?;
+                if object.get_type() == ObjectType::Image
+                    && object.has_transparency() == 0
+                    && downsample_image(self, &page, &object, max_dpi, jpeg_quality)?
+                {
+                    modified += 1;
+                    page_modified = true;
+                }
+            }
+            if page_modified {
+                page.generate_content()?;
+            }
+        }
+        Ok(modified)
+    }
+
+    /// Removes the additional actions (AA) of all annotations, returning the number removed.
+    ///
+    /// Additional actions are run on events like the mouse entering an annotation or a form
+    /// field being changed, and are the usual carrier of annotation JavaScript. PDFium cannot
+    /// delete dictionary entries, so each AA entry is replaced by an empty string, which
+    /// viewers ignore, together with any non-JavaScript actions it held.
+    ///
+    /// PDFium offers no way to change the document catalog or the parent fields of form
+    /// widgets, so document-level JavaScript (the `Names/JavaScript` tree, see
+    /// [`PdfiumDocument::javascript_action_count`]), the document open action and actions of
+    /// parent form fields are left in place.
+    pub fn remove_all_javascript(&self) -> PdfiumResult<usize> {
+        let key = CString::new("AA")?;
+        let mut removed = 0;
+        for page in self.pages() {
+            let page = page?;
+            for index in 0..page.annotation_count() {
+                let annotation = page.annotation(index)?;
+                if lib().FPDFAnnot_GetValueType(&annotation, &key) == FPDF_OBJECT_DICTIONARY {
+                    lib().FPDFAnnot_SetStringValue(&annotation, &key, "")?;
+                    removed += 1;
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Returns the number of document-level JavaScript actions in this [`PdfiumDocument`].
+    pub fn javascript_action_count(&self) -> i32 {
+        lib().FPDFDoc_GetJavaScriptActionCount(self)
+    }
+
+    /// Enables or disables automatic content generation for edited pages.
+    ///
+    /// Changes to page objects (colors, matrices, inserted objects, ...) only live in memory
+    /// until the content stream of their page is regenerated. By default this
+    /// [`PdfiumDocument`] keeps track of edited pages and regenerates their content before
+    /// saving, see [`PdfiumDocument::ensure_content_generated`].
+    ///
+    /// Advanced users who prefer to call [`PdfiumPage::generate_content`] themselves can
+    /// disable this. Pages that were already marked as edited are forgotten.
+    pub fn set_auto_generate_content(&self, enabled: bool) {
+        self.auto_generate_content.set(enabled);
+        if !enabled {
+            self.dirty_pages.borrow_mut().clear();
+        }
+    }
+
+    /// Returns `true` if edited pages are regenerated automatically before saving.
+    pub fn auto_generate_content(&self) -> bool {
+        self.auto_generate_content.get()
+    }
+
+    /// Sets a hook that is called with the index and load time of every page loaded through
+    /// [`PdfiumDocument::page`], replacing any previous hook.
+    ///
+    /// Useful for finding slow pages or driving a page cache. The hook is shared with all
+    /// clones of this [`PdfiumDocument`] and with the pages loaded from it. Pages loaded from
+    /// within the hook itself do not trigger it again. Without a hook, no timing is done.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within the hook.
+    pub fn on_page_load<F>(&self, f: F)
+    where
+        F: FnMut(i32, Duration) + 'static,
+    {
+        *self.page_load_hook.borrow_mut() = Some(Box::new(f));
+    }
+
+    /// Removes the hook set with [`PdfiumDocument::on_page_load`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within the hook.
+    pub fn clear_page_load_hook(&self) {
+        self.page_load_hook.borrow_mut().take();
+    }
+
+    /// Regenerates the content stream of every page that was edited since its content was
+    /// last generated.
+    ///
+    /// This is called by all `save_to_*` methods, so it rarely needs to be called directly.
+    /// Edited pages are kept loaded until their content has been generated.
+    pub fn ensure_content_generated(&self) -> PdfiumResult<()> {
+        let pages = self.dirty_pages.borrow().clone();
+        for handle in pages {
+            let page = PdfiumPage::new_from_page_handle(handle.clone());
+            lib().FPDFPage_GenerateContent(&page)?;
+            self.clear_page_dirty(&handle);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn mark_page_dirty(&self, page: &PageHandle) {
+        if self.auto_generate_content.get() && !self.is_page_dirty(page) {
+            self.dirty_pages.borrow_mut().push(page.clone());
+        }
+    }
+
+    pub(crate) fn is_page_dirty(&self, page: &PageHandle) -> bool {
+        self.dirty_pages
+            .borrow()
+            .iter()
+            .any(|dirty| dirty.handle() == page.handle())
+    }
+
+    pub(crate) fn clear_page_dirty(&self, page: &PageHandle) {
+        self.dirty_pages
+            .borrow_mut()
+            .retain(|dirty| dirty.handle() != page.handle());
     }
 
     /// Saves this [`PdfiumDocument`] to a file at the specified path.
@@ -233,6 +529,11 @@ impl PdfiumDocument {
     /// let buffer = document.save_to_writer(buffer, None)?;
     /// ```
     ///
+    /// # Edited Pages
+    ///
+    /// Unless disabled with [`PdfiumDocument::set_auto_generate_content`], the content of
+    /// all edited pages is regenerated before saving.
+    ///
     /// # Implementation Details
     ///
     /// The method uses the Pdfium library's C API functions:
@@ -247,9 +548,35 @@ impl PdfiumDocument {
         version: Option<i32>,
     ) -> PdfiumResult<Box<W>> {
         // Set flags to 0 - this typically means "use default behavior" in Pdfium.
-        // Other flag values might control incremental updates, linearization,
-        // object stream compression, etc., but aren't used in this implementation.
-        let flags = 0;
+        self.save_with_flags(writer, version, 0)
+    }
+
+    /// Writes this [`PdfiumDocument`] to the given writer as an incremental update.
+    ///
+    /// The original file is written unmodified, followed by only the objects that changed
+    /// since the document was loaded. Because the originally signed bytes stay exactly the
+    /// same, this preserves existing digital signatures, whereas a full rewrite using
+    /// [`PdfiumDocument::save_to_writer`] invalidates them.
+    ///
+    /// Use this to, for example, fill in forms or add annotations to a signed PDF.
+    /// Changes to the page content of signed pages may still be flagged by signature
+    /// validators, depending on the permissions granted by the signature.
+    pub fn save_incremental_to_writer<W: Write + 'static>(
+        &self,
+        writer: W,
+    ) -> PdfiumResult<Box<W>> {
+        self.save_with_flags(writer, None, FPDF_INCREMENTAL as FPDF_DWORD)
+    }
+
+    fn save_with_flags<W: Write + 'static>(
+        &self,
+        writer: W,
+        version: Option<i32>,
+        flags: FPDF_DWORD,
+    ) -> PdfiumResult<Box<W>> {
+        if self.auto_generate_content.get() {
+            self.ensure_content_generated()?;
+        }
 
         let mut pdfium_writer = PdfiumWriter::new(writer);
 
@@ -283,17 +610,607 @@ impl PdfiumDocument {
         lib().FPDF_GetPageCount(self)
     }
 
+    /// Returns the size (width, height) in points of the page at `index`, without loading
+    /// the page.
+    pub fn page_size(&self, index: i32) -> PdfiumResult<(f32, f32)> {
+        let mut size = FS_SIZEF {
+            width: 0.0,
+            height: 0.0,
+        };
+        lib().FPDF_GetPageSizeByIndexF(self, index, &mut size)?;
+        Ok((size.width, size.height))
+    }
+
+    /// Returns the sizes (width, height) in points of all pages, without loading them.
+    ///
+    /// Useful to lay out a viewer (e.g. the total scroll height) before rendering any page.
+    pub fn page_sizes(&self) -> PdfiumResult<Vec<(f32, f32)>> {
+        (0..self.page_count())
+            .map(|index| self.page_size(index))
+            .collect()
+    }
+
+    /// Returns the aspect ratios (width / height) of all pages, without loading them.
+    pub fn page_aspect_ratios(&self) -> PdfiumResult<Vec<f32>> {
+        Ok(self
+            .page_sizes()?
+            .into_iter()
+            .map(|(width, height)| width / height)
+            .collect())
+    }
+
+    /// Returns the file identifier of `id_type` from the trailer of this [`PdfiumDocument`].
+    ///
+    /// `id_type` is one of the `FPDF_FILEIDTYPE_*` constants from [`crate::pdfium_constants`].
+    /// Returns `None` if the document has no `/ID` entry.
+    pub fn file_identifier(&self, id_type: FPDF_FILEIDTYPE) -> Option<Vec<u8>> {
+        let lib = lib();
+        let len = lib.FPDF_GetFileIdentifier(self, id_type, None, 0);
+        if len <= 1 {
+            return None;
+        }
+        let mut buffer = vec![0u8; len as usize];
+        lib.FPDF_GetFileIdentifier(self, id_type, Some(&mut buffer), len);
+        buffer.pop(); // NUL terminator
+        Some(buffer)
+    }
+
+    /// Returns a stable identifier for this [`PdfiumDocument`], e.g. to cache rendered pages.
+    ///
+    /// This is the permanent file identifier as a lowercase hex string. Documents without a
+    /// file identifier fall back to a hash of their saved bytes, ignoring the identifier that
+    /// PDFium generates when saving. Returns `None` if the document cannot be saved.
+    pub fn cache_key(&self) -> Option<String> {
+        if let Some(id) = self.file_identifier(FPDF_FILEIDTYPE_FILEIDTYPE_PERMANENT) {
+            return Some(id.iter().map(|byte| format!("{byte:02x}")).collect());
+        }
+        let bytes = self.save_to_bytes(None).ok()?;
+        Some(format!("{:016x}", content_hash(&bytes)))
+    }
+
+    /// Returns the number of digital signatures in this [`PdfiumDocument`].
+    pub fn signature_count(&self) -> i32 {
+        lib().FPDF_GetSignatureCount(self)
+    }
+
+    /// Returns the [`PdfiumSignature`] indicated by `index` from this [`PdfiumDocument`].
+    pub fn signature(&self, index: i32) -> PdfiumResult<PdfiumSignature> {
+        let mut signature = lib().FPDF_GetSignatureObject(self, index)?;
+        signature.set_owner(self.clone());
+        Ok(signature)
+    }
+
+    /// Returns the [`PdfiumAvailability`] of a [`PdfiumDocument`] that was loaded using
+    /// [`PdfiumDocument::new_from_file_range`].
+    pub fn availability(&self) -> Option<&PdfiumAvailability> {
+        self.availability.as_ref()
+    }
+
+    /// Returns the zero-based index of the first available page.
+    ///
+    /// For most linearized PDFs this is the first page. For non-linearized PDFs it is
+    /// always zero.
+    pub fn first_available_page(&self) -> i32 {
+        lib().FPDFAvail_GetFirstPageNum(self)
+    }
+
+    /// Returns `true` if enough data is available to load the page at `index`.
+    ///
+    /// Always returns `true` for documents that were fully available when loaded.
+    pub fn is_page_available(&self, index: i32) -> PdfiumResult<bool> {
+        match &self.availability {
+            Some(availability) => availability.is_page_available(index),
+            None => Ok(true),
+        }
+    }
+
     /// Returns the [`PdfiumPage`] indicated by `index` from this [`PdfiumDocument`].
     pub fn page(&self, index: i32) -> PdfiumResult<PdfiumPage> {
+        let start = self
+            .page_load_hook
+            .try_borrow()
+            .is_ok_and(|hook| hook.is_some())
+            .then(Instant::now);
         let mut page = lib().FPDF_LoadPage(self, index)?;
         page.set_owner(self.clone());
+        if let Some(start) = start {
+            if let Ok(mut hook) = self.page_load_hook.try_borrow_mut() {
+                if let Some(hook) = hook.as_mut() {
+                    hook(index, start.elapsed());
+                }
+            }
+        }
+        Ok(page)
+    }
+
+    /// Returns the first [`PdfiumPage`] of this [`PdfiumDocument`].
+    ///
+    /// Returns [`PdfiumError::PageError`] if the document has no pages.
+    pub fn first_page(&self) -> PdfiumResult<PdfiumPage> {
+        if self.page_count() < 1 {
+            return Err(PdfiumError::PageError);
+        }
+        self.page(0)
+    }
+
+    /// Returns the last [`PdfiumPage`] of this [`PdfiumDocument`].
+    ///
+    /// Returns [`PdfiumError::PageError`] if the document has no pages.
+    pub fn last_page(&self) -> PdfiumResult<PdfiumPage> {
+        let page_count = self.page_count();
+        if page_count < 1 {
+            return Err(PdfiumError::PageError);
+        }
+        self.page(page_count - 1)
+    }
+
+    /// Inserts a blank page at index `at` with the same size and rotation as the page at
+    /// `template_index`, and returns the new [`PdfiumPage`].
+    ///
+    /// The size is taken from the template's [`PdfiumPageBoundaries::default`] box. Note that
+    /// `template_index` refers to the document before the insertion.
+    ///
+    /// Returns [`PdfiumError::PageError`] if `template_index` or `at` is out of range.
+    ///
+    /// [`PdfiumPageBoundaries::default`]: crate::page::boundaries::PdfiumPageBoundaries::default
+    pub fn insert_blank_page_like(&self, template_index: i32, at: i32) -> PdfiumResult<PdfiumPage> {
+        let page_count = self.page_count();
+        if template_index < 0 || template_index >= page_count || at < 0 || at > page_count {
+            return Err(PdfiumError::PageError);
+        }
+        let template = self.page(template_index)?;
+        let bounds = template.boundaries().default()?;
+        let lib = lib();
+        let rotation = lib.FPDFPage_GetRotation(&template);
+        let page = lib.FPDFPage_New(self, at, bounds.width() as f64, bounds.height() as f64)?;
+        lib.FPDFPage_SetRotation(&page, rotation);
         Ok(page)
     }
 
+    /// Bakes the page rotations of this [`PdfiumDocument`] into the page content.
+    ///
+    /// See [`PdfiumPage::bake_rotation`]. Afterwards every page has a rotation of 0, while
+    /// looking the same as before.
+    pub fn bake_rotations(&self) -> PdfiumResult<()> {
+        for page in self.pages() {
+            page?.bake_rotation()?;
+        }
+        Ok(())
+    }
+
+    /// Sets the CropBox of every page of this [`PdfiumDocument`] to `rect`.
+    ///
+    /// Returns [`PdfiumError::InvalidConfiguration`] without changing any page if `rect` does
+    /// not fit within the MediaBox of every page.
+    pub fn set_all_crop_boxes(&self, rect: PdfiumRect) -> PdfiumResult<()> {
+        self.set_all_boxes(rect, "CropBox", |boundaries, rect| {
+            boundaries.set_crop(rect)
+        })
+    }
+
+    /// Sets the BleedBox of every page of this [`PdfiumDocument`] to `rect`.
+    ///
+    /// Returns [`PdfiumError::InvalidConfiguration`] without changing any page if `rect` does
+    /// not fit within the MediaBox of every page.
+    pub fn set_all_bleed_boxes(&self, rect: PdfiumRect) -> PdfiumResult<()> {
+        self.set_all_boxes(rect, "BleedBox", |boundaries, rect| {
+            boundaries.set_bleed(rect)
+        })
+    }
+
+    /// Sets the TrimBox of every page of this [`PdfiumDocument`] to `rect`.
+    ///
+    /// Returns [`PdfiumError::InvalidConfiguration`] without changing any page if `rect` does
+    /// not fit within the MediaBox of every page.
+    pub fn set_all_trim_boxes(&self, rect: PdfiumRect) -> PdfiumResult<()> {
+        self.set_all_boxes(rect, "TrimBox", |boundaries, rect| {
+            boundaries.set_trim(rect)
+        })
+    }
+
+    /// Sets the ArtBox of every page of this [`PdfiumDocument`] to `rect`.
+    ///
+    /// Returns [`PdfiumError::InvalidConfiguration`] without changing any page if `rect` does
+    /// not fit within the MediaBox of every page.
+    pub fn set_all_art_boxes(&self, rect: PdfiumRect) -> PdfiumResult<()> {
+        self.set_all_boxes(rect, "ArtBox", |boundaries, rect| boundaries.set_art(rect))
+    }
+
+    fn set_all_boxes(
+        &self,
+        rect: PdfiumRect,
+        name: &str,
+        set: fn(&PdfiumPageBoundaries, PdfiumRect),
+    ) -> PdfiumResult<()> {
+        let pages = self.pages().collect::<PdfiumResult<Vec<_>>>()?;
+        for page in &pages {
+            // A MediaBox inherited from the page tree is not in the page dictionary
+            let boundaries = page.boundaries();
+            let media = boundaries.media().or_else(|_| boundaries.bounding_box())?;
+            if !(media.contains(rect.left, rect.bottom) && media.contains(rect.right, rect.top)) {
+                return Err(PdfiumError::InvalidConfiguration(format!(
+                    "{name} must fit within the MediaBox of every page"
+                )));
+            }
+        }
+        for page in &pages {
+            set(&page.boundaries(), rect);
+        }
+        Ok(())
+    }
+
+    /// Deletes the page at `index` from this [`PdfiumDocument`].
+    ///
+    /// Returns [`PdfiumError::PageError`] if `index` is out of range.
+    pub fn delete_page(&self, index: i32) -> PdfiumResult<()> {
+        if index < 0 || index >= self.page_count() {
+            return Err(PdfiumError::PageError);
+        }
+        lib().FPDFPage_Delete(self, index);
+        Ok(())
+    }
+
+    /// Replaces the page at `index` with page `src_index` of `src_doc`.
+    ///
+    /// The source page is imported at `index` before the original page is deleted, so the
+    /// order of the surrounding pages is preserved and nothing is deleted if the import fails.
+    ///
+    /// Returns [`PdfiumError::PageError`] if `index` or `src_index` is out of range.
+    pub fn replace_page(
+        &self,
+        index: i32,
+        src_doc: &PdfiumDocument,
+        src_index: i32,
+    ) -> PdfiumResult<()> {
+        if index < 0 || index >= self.page_count() || src_index < 0 {
+            return Err(PdfiumError::PageError);
+        }
+        if src_index >= src_doc.page_count() {
+            return Err(PdfiumError::PageError);
+        }
+        self.pages()
+            .import_by_index(src_doc, Some(&[src_index]), index)?;
+        self.delete_page(index + 1)
+    }
+
+    /// Deletes the pages in `range` from this [`PdfiumDocument`].
+    ///
+    /// Pages are deleted in descending order, so the indices in `range` all refer to the
+    /// document as it was before the call. Duplicate pages in `range` are ignored.
+    ///
+    /// Returns [`PdfiumError::PageError`] without deleting anything if any page in
+    /// `range` is out of range.
+    pub fn remove_pages(&self, range: &PdfiumPageSelection) -> PdfiumResult<()> {
+        let page_count = self.page_count();
+        let mut remove = vec![false; page_count.max(0) as usize];
+        for pages in range.ranges().iter().cloned() {
+            if pages.is_empty() {
+                continue;
+            }
+            if pages.start < 0 || pages.end > page_count {
+                return Err(PdfiumError::PageError);
+            }
+            remove[pages.start as usize..pages.end as usize].fill(true);
+        }
+        let lib = lib();
+        for index in (0..page_count)
+            .rev()
+            .filter(|&index| remove[index as usize])
+        {
+            lib.FPDFPage_Delete(self, index);
+        }
+        Ok(())
+    }
+
+    /// Returns the first top-level [`PdfiumBookmark`] of the outline of this
+    /// [`PdfiumDocument`], or `None` if the document has no outline.
+    ///
+    /// Use [`PdfiumBookmark::first_child`] and [`PdfiumBookmark::next_sibling`] to walk
+    /// the bookmark tree.
+    pub fn first_bookmark(&self) -> Option<PdfiumBookmark> {
+        self.bookmark_child(None)
+    }
+
+    /// Returns a depth-first iterator over all bookmarks of this [`PdfiumDocument`], yielding
+    /// `(depth, bookmark)` pairs.
+    ///
+    /// Guards against circular references in the outline, see [`PdfiumBookmarks`].
+    pub fn bookmarks(&self) -> PdfiumBookmarks {
+        PdfiumBookmarks::new(self.first_bookmark())
+    }
+
+    pub(crate) fn bookmark_child(&self, parent: Option<&PdfiumBookmark>) -> Option<PdfiumBookmark> {
+        let mut child = lib().FPDFBookmark_GetFirstChild(self, parent).ok()?;
+        child.set_owner(self.clone());
+        Some(child)
+    }
+
+    /// Returns the outline (bookmark tree) of this [`PdfiumDocument`] as JSON.
+    ///
+    /// The outline is a nested array of `{"title": ..., "page": ..., "children": [...]}`
+    /// objects, where `page` is the zero-based page index or `null` if the bookmark does
+    /// not point to a page. The outline is walked with [`PdfiumDocument::bookmarks`], so
+    /// bookmarks that are referenced more than once appear only once.
+    #[cfg(feature = "json")]
+    pub fn outline_json(&self) -> PdfiumResult<String> {
+        use serde_json::{json, Value};
+        use std::iter::Peekable;
+
+        /// Consumes the bookmarks at `depth` and their descendants, up to the next bookmark
+        /// at a smaller depth.
+        fn nodes(bookmarks: &mut Peekable<PdfiumBookmarks>, depth: i32) -> Vec<Value> {
+            let mut siblings = Vec::new();
+            while let Some((_, bookmark)) = bookmarks.next_if(|(d, _)| *d == depth) {
+                siblings.push(json!({
+                    "title": bookmark.title(),
+                    "page": bookmark.page_index(),
+                    "children": nodes(bookmarks, depth + 1),
+                }));
+            }
+            siblings
+        }
+
+        let outline = nodes(&mut self.bookmarks().peekable(), 0);
+        serde_json::to_string(&outline).map_err(|_| PdfiumError::InvokationFailed)
+    }
+
     /// Return an [`Iterator`] for the pages in this [`PdfiumDocument`].
     pub fn pages(&self) -> PdfiumPages<'_> {
         PdfiumPages::new(self)
     }
+
+    /// Returns the total number of page objects on all pages of this [`PdfiumDocument`].
+    ///
+    /// Useful as a quick estimate of the complexity of a document. Pages are loaded one at
+    /// a time and dropped after counting.
+    pub fn total_object_count(&self) -> PdfiumResult<i64> {
+        self.pages()
+            .map(|page| Ok(page?.object_count() as i64))
+            .sum()
+    }
+
+    /// Returns the total number of annotations on all pages of this [`PdfiumDocument`].
+    ///
+    /// Pages are loaded one at a time and dropped after counting.
+    pub fn total_annotation_count(&self) -> PdfiumResult<i64> {
+        self.pages()
+            .map(|page| Ok(page?.annotation_count() as i64))
+            .sum()
+    }
+
+    /// Returns a summary of all annotations on all pages of this [`PdfiumDocument`].
+    ///
+    /// Pages are loaded one at a time and dropped after collecting their annotations. Useful
+    /// for e.g. a comments panel in a review application.
+    pub fn all_annotations(&self) -> PdfiumResult<Vec<PdfiumAnnotationRef>> {
+        let mut annotations = Vec::new();
+        for (page_index, page) in self.pages().enumerate() {
+            let page = page?;
+            for index in 0..page.annotation_count() {
+                let annotation = page.annotation(index)?;
+                annotations.push(PdfiumAnnotationRef {
+                    page_index: page_index as i32,
+                    subtype: annotation.subtype(),
+                    rect: annotation.rect()?,
+                    contents: annotation.contents(),
+                });
+            }
+        }
+        Ok(annotations)
+    }
+
+    /// Returns an [`Iterator`] that renders each page of this [`PdfiumDocument`] to a
+    /// [`DynamicImage`] using `config`.
+    ///
+    /// Pages are rendered lazily while iterating, so only one rendered page is held in
+    /// memory at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let config = PdfiumRenderConfig::new().with_width(1920);
+    /// for (index, image) in document.images_per_page(&config).enumerate() {
+    ///     image?.save(format!("page-{}.png", index + 1))?;
+    /// }
+    /// ```
+    pub fn images_per_page<'a>(
+        &'a self,
+        config: &'a PdfiumRenderConfig,
+    ) -> impl Iterator<Item = PdfiumResult<DynamicImage>> + 'a {
+        self.pages()
+            .map(move |page| page?.render(config)?.as_rgba8_image())
+    }
+
+    /// Extracts the full text of each page in `range` from this [`PdfiumDocument`].
+    ///
+    /// Returns a `(page_index, text)` pair for every page in the range, in range order.
+    /// Page indices are zero-based.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let range = PdfiumPageSelection::parse("5-10")?;
+    /// for (index, text) in document.text_in_range(&range)? {
+    ///     println!("Page {}: {text}", index + 1);
+    /// }
+    /// ```
+    pub fn text_in_range(&self, range: &PdfiumPageSelection) -> PdfiumResult<Vec<(i32, String)>> {
+        range
+            .indices()
+            .map(|index| Ok((index, self.page(index)?.text()?.full())))
+            .collect()
+    }
+
+    /// Calls `f` with the index and text of each page of this [`PdfiumDocument`] in order.
+    ///
+    /// Each page and its text are dropped before the next page is loaded, so memory use stays
+    /// flat regardless of the number of pages, unlike collecting all text at once. Return
+    /// [`ControlFlow::Break`] from `f` to stop early.
+    pub fn for_each_page_text<F>(&self, mut f: F) -> PdfiumResult<()>
+    where
+        F: FnMut(i32, &str) -> ControlFlow<()>,
+    {
+        for index in 0..self.page_count() {
+            let text = self.page(index)?.text()?.full();
+            if f(index, &text).is_break() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the indices of the pages whose text contains `query`.
+    ///
+    /// Each page is searched until its first match only, see [`crate::PdfiumTextPage::find`] for
+    /// the meaning of `flags`.
+    pub fn pages_containing(
+        &self,
+        query: &str,
+        flags: PdfiumSearchFlags,
+    ) -> PdfiumResult<Vec<i32>> {
+        let mut indices = Vec::new();
+        for index in 0..self.page_count() {
+            if self
+                .page(index)?
+                .text()?
+                .find(query, flags, 0)
+                .next()
+                .is_some()
+            {
+                indices.push(index);
+            }
+        }
+        Ok(indices)
+    }
+
+    /// Searches all pages for `query` and returns every match with `context_chars`
+    /// characters of surrounding text, e.g. to list search results with a snippet.
+    ///
+    /// See [`crate::PdfiumTextPage::find`] for the meaning of `flags`.
+    pub fn search_with_context(
+        &self,
+        query: &str,
+        flags: PdfiumSearchFlags,
+        context_chars: usize,
+    ) -> PdfiumResult<Vec<PdfiumSearchHit>> {
+        let context = context_chars.min(i32::MAX as usize) as i32;
+        let mut hits = Vec::new();
+        for page_index in 0..self.page_count() {
+            let text = self.page(page_index)?.text()?;
+            let char_count = text.char_count()?;
+            for result in text.find(query, flags, 0) {
+                let start = result.index().saturating_sub(context).max(0);
+                let end = result
+                    .index()
+                    .saturating_add(result.count())
+                    .saturating_add(context)
+                    .min(char_count);
+                // The context may split a surrogate pair at either end
+                let snippet = text.extract(start, end - start);
+                let snippet = snippet.trim_matches(char::REPLACEMENT_CHARACTER);
+                hits.push(PdfiumSearchHit {
+                    page_index,
+                    char_index: result.index(),
+                    text: text.extract(result.index(), result.count()),
+                    snippet: snippet.split_whitespace().collect::<Vec<_>>().join(" "),
+                });
+            }
+        }
+        Ok(hits)
+    }
+}
+
+impl PdfiumDocument {
+    /// Processes all pages of the PDF contained in `bytes` on `workers` threads.
+    ///
+    /// PDFium documents must not be shared between threads, so every worker loads its own
+    /// [`PdfiumDocument`] from the shared `bytes` and calls `f` for a contiguous subset of
+    /// the pages. The results are returned in page order.
+    ///
+    /// Note that all calls into PDFium are serialized by the library guard, so the speedup
+    /// comes from the work `f` does outside of PDFium.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let bytes: Arc<[u8]> = std::fs::read("document.pdf")?.into();
+    /// let texts = PdfiumDocument::pages_par_iter(bytes, None, 4, |_index, page| {
+    ///     Ok(page.text()?.full())
+    /// })?;
+    /// ```
+    pub fn pages_par_iter<T, F>(
+        bytes: Arc<[u8]>,
+        password: Option<&str>,
+        workers: usize,
+        f: F,
+    ) -> PdfiumResult<Vec<T>>
+    where
+        T: Send,
+        F: Fn(i32, &PdfiumPage) -> PdfiumResult<T> + Sync,
+    {
+        let page_count = Self::new_from_reader(Cursor::new(bytes.clone()), password)?.page_count();
+        let workers = workers.clamp(1, page_count.max(1) as usize) as i32;
+        let chunk_size = (page_count + workers - 1) / workers;
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|worker| {
+                    let bytes = bytes.clone();
+                    let f = &f;
+                    scope.spawn(move || -> PdfiumResult<Vec<T>> {
+                        let first = worker * chunk_size;
+                        let last = (first + chunk_size).min(page_count);
+                        let document = Self::new_from_reader(Cursor::new(bytes), password)?;
+                        (first..last)
+                            .map(|index| f(index, &document.page(index)?))
+                            .collect()
+                    })
+                })
+                .collect();
+
+            let mut results = Vec::with_capacity(page_count as usize);
+            for handle in handles {
+                let chunk = handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))?;
+                results.extend(chunk);
+            }
+            Ok(results)
+        })
+    }
+
+    /// Renders a thumbnail of every page of this [`PdfiumDocument`], e.g. for a file browser.
+    ///
+    /// Each thumbnail fits within `max_dim` x `max_dim` pixels, keeping the aspect ratio of
+    /// its page. With a `concurrency` above 1 the pages are rendered by that many workers,
+    /// each on its own copy of the document, see [`PdfiumDocument::pages_par_iter`]. The copy
+    /// is made by saving this document, so password protected documents can only be rendered
+    /// with a `concurrency` of 1. The thumbnails are returned in page order.
+    pub fn thumbnails(&self, max_dim: u32, concurrency: usize) -> PdfiumResult<Vec<DynamicImage>> {
+        if max_dim == 0 || max_dim > i32::MAX as u32 {
+            return Err(PdfiumError::InvalidConfiguration(
+                "Thumbnail size must be positive".to_string(),
+            ));
+        }
+        let render = move |_index: i32, page: &PdfiumPage| {
+            let bounds = page.boundaries().default()?;
+            let config = if bounds.width() >= bounds.height() {
+                PdfiumRenderConfig::new().with_width(max_dim as i32)
+            } else {
+                PdfiumRenderConfig::new().with_height(max_dim as i32)
+            };
+            page.render(&config)?.as_rgba8_image()
+        };
+        if concurrency <= 1 {
+            return self
+                .pages()
+                .enumerate()
+                .map(|(index, page)| render(index as i32, &page?))
+                .collect();
+        }
+        let bytes: Arc<[u8]> = self.save_to_bytes(None)?.into();
+        Self::pages_par_iter(bytes, None, concurrency, render)
+    }
 }
 
 impl From<&PdfiumDocument> for FPDF_DOCUMENT {
@@ -303,14 +1220,129 @@ impl From<&PdfiumDocument> for FPDF_DOCUMENT {
     }
 }
 
+/// Returns the error of the last failed document load.
+fn last_error() -> PdfiumResult<PdfiumError> {
+    Ok(match try_lib()?.FPDF_GetLastError() as i32 {
+        crate::pdfium_constants::FPDF_ERR_FILE => PdfiumError::FileError,
+        crate::pdfium_constants::FPDF_ERR_FORMAT => PdfiumError::FormatError,
+        crate::pdfium_constants::FPDF_ERR_PASSWORD => PdfiumError::PasswordError,
+        crate::pdfium_constants::FPDF_ERR_SECURITY => PdfiumError::SecurityError,
+        crate::pdfium_constants::FPDF_ERR_PAGE => PdfiumError::PageError,
+        _ => PdfiumError::Unknown,
+    })
+}
+
 /// Closes this [PdfiumDocument], releasing held memory.
 fn close_document(document: FPDF_DOCUMENT) {
     lib().FPDF_CloseDocument(document);
 }
 
+/// FNV-1a hash of a saved document, skipping the last `/ID [...]` entry as PDFium fills it
+/// with a random identifier when the document did not have one.
+fn content_hash(bytes: &[u8]) -> u64 {
+    let id_span = bytes
+        .windows(3)
+        .rposition(|window| window == b"/ID")
+        .and_then(|start| {
+            let end = bytes[start..].iter().position(|&byte| byte == b']')?;
+            Some(start..start + end + 1)
+        });
+    let (head, tail) = match id_span {
+        Some(span) => (&bytes[..span.start], &bytes[span.end..]),
+        None => (bytes, &[][..]),
+    };
+    head.iter()
+        .chain(tail)
+        .fold(0xcbf29ce484222325, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+        })
+}
+
+/// Replaces the image of `object` by a JPEG at `max_dpi` if it is displayed at a higher
+/// resolution. Returns `true` if the image was replaced.
+fn downsample_image(
+    document: &PdfiumDocument,
+    page: &PdfiumPage,
+    object: &PdfiumPageObject,
+    max_dpi: f32,
+    jpeg_quality: u8,
+) -> PdfiumResult<bool> {
+    let (mut width, mut height) = (0, 0);
+    lib().FPDFImageObj_GetImagePixelSize(object, &mut width, &mut height)?;
+    let mut matrix: FS_MATRIX = (&PdfiumMatrix::new_scale(1.0)).into();
+    object.get_matrix(&mut matrix)?;
+
+    // The object matrix maps the unit square of the image onto the page (in points)
+    let shown_width = matrix.a.hypot(matrix.b) / 72.0;
+    let shown_height = matrix.c.hypot(matrix.d) / 72.0;
+    if width == 0 || height == 0 || shown_width <= 0.0 || shown_height <= 0.0 {
+        return Ok(false);
+    }
+    let dpi = (width as f32 / shown_width).max(height as f32 / shown_height);
+    if dpi <= max_dpi || has_mask(document, page, object) {
+        return Ok(false);
+    }
+    let Ok(image) = lib().FPDFImageObj_GetBitmap(object)?.as_rgb8_image() else {
+        return Ok(false);
+    };
+
+    let scale = max_dpi / dpi;
+    let new_width = ((width as f32 * scale).round() as u32).max(1);
+    let new_height = ((height as f32 * scale).round() as u32).max(1);
+    let resized = image.resize_exact(new_width, new_height, FilterType::Triangle);
+    let mut jpeg = Vec::new();
+    resized
+        .write_with_encoder(JpegEncoder::new_with_quality(&mut jpeg, jpeg_quality))
+        .or(Err(PdfiumError::ImageError))?;
+
+    let mut reader = PdfiumReader::new(Cursor::new(jpeg));
+    let mut pages: FPDF_PAGE = page.into();
+    lib().FPDFImageObj_LoadJpegFileInline(&mut pages, 1, object.into(), reader.as_mut().into())?;
+    Ok(true)
+}
+
+/// Returns `true` if the image `object` is a stencil mask (/ImageMask) or is drawn through a
+/// mask, so it cannot be replaced by an opaque JPEG.
+///
+/// PDFium does not expose the image dictionary. Stencil masks are recognized by their
+/// metadata (1 bit per pixel without a color space), other masks by rendering the image with
+/// its mask applied and looking for transparent pixels. Images that fail to render count as
+/// masked.
+fn has_mask(document: &PdfiumDocument, page: &PdfiumPage, object: &PdfiumPageObject) -> bool {
+    let mut metadata = FPDF_IMAGEOBJ_METADATA {
+        width: 0,
+        height: 0,
+        horizontal_dpi: 0.0,
+        vertical_dpi: 0.0,
+        bits_per_pixel: 0,
+        colorspace: FPDF_COLORSPACE_UNKNOWN,
+        marked_content_id: -1,
+    };
+    if lib()
+        .FPDFImageObj_GetImageMetadata(object, page, &mut metadata)
+        .is_ok()
+        && metadata.bits_per_pixel == 1
+        && metadata.colorspace == FPDF_COLORSPACE_UNKNOWN
+    {
+        return true;
+    }
+    lib()
+        .FPDFImageObj_GetRenderedBitmap(document, page, object)
+        .and_then(|bitmap| bitmap.as_rgba_bytes())
+        .map_or(true, |rgba| {
+            rgba.chunks_exact(4).any(|pixel| pixel[3] < 255)
+        })
+}
+
 #[cfg(test)]
 mod tests {
+    use std::fs;
+
     use super::*;
+    use crate::{
+        pdfium_constants::{FPDF_ANNOT_SQUARE, FPDF_ANNOT_TEXT},
+        PdfiumColor, PdfiumRect,
+    };
 
     #[test]
     fn test_load_non_existing() {
@@ -338,6 +1370,252 @@ mod tests {
         assert_eq!(page_count, 2);
     }
 
+    /// Reader that fails once it is asked for data beyond `limit`
+    struct FailingReader {
+        inner: Cursor<Vec<u8>>,
+        limit: u64,
+    }
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.inner.position() + buf.len() as u64 > self.limit {
+                return Err(std::io::Error::other("connection reset"));
+            }
+            self.inner.read(buf)
+        }
+    }
+
+    impl Seek for FailingReader {
+        fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+            self.inner.seek(pos)
+        }
+    }
+
+    #[test]
+    fn test_load_failing_reader() {
+        let bytes = fs::read("resources/groningen.pdf").unwrap();
+        let reader = FailingReader {
+            limit: bytes.len() as u64 / 2,
+            inner: Cursor::new(bytes),
+        };
+        let Err(err) = PdfiumDocument::new_from_reader(reader, None) else {
+            panic!("loading from a failing reader should fail");
+        };
+        match err {
+            PdfiumError::IoError(message) => assert!(message.contains("connection reset")),
+            other => panic!("expected an IO error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_cache_key() {
+        // With a file identifier in the trailer
+        let bytes = fs::read("resources/chapter1.pdf").unwrap();
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes.clone()), None).unwrap();
+        let key = document.cache_key().unwrap();
+        assert_eq!(key.len(), 32);
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        assert_eq!(document.cache_key().unwrap(), key);
+
+        // Without a file identifier
+        let bytes = fs::read("resources/groningen.pdf").unwrap();
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes.clone()), None).unwrap();
+        assert!(document
+            .file_identifier(FPDF_FILEIDTYPE_FILEIDTYPE_PERMANENT)
+            .is_none());
+        let fallback = document.cache_key().unwrap();
+        assert_eq!(fallback.len(), 16);
+        assert_eq!(document.cache_key().unwrap(), fallback);
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        assert_eq!(document.cache_key().unwrap(), fallback);
+        assert_ne!(fallback, key);
+    }
+
+    #[test]
+    fn test_all_annotations() {
+        let document = PdfiumDocument::new().unwrap();
+        let contents = CString::new("Contents").unwrap();
+        for index in 0..3 {
+            let page = lib().FPDFPage_New(&document, index, 200.0, 200.0).unwrap();
+            // No annotations on the second page
+            if index == 1 {
+                continue;
+            }
+            let note = lib().FPDFPage_CreateAnnot(&page, FPDF_ANNOT_TEXT).unwrap();
+            let rect = PdfiumRect::new_from_lbrt((10.0, 10.0, 30.0, 30.0));
+            lib().FPDFAnnot_SetRect(&note, &(&rect).into()).unwrap();
+            lib()
+                .FPDFAnnot_SetStringValue(&note, &contents, &format!("Note on page {index}"))
+                .unwrap();
+        }
+        let page = document.page(2).unwrap();
+        lib()
+            .FPDFPage_CreateAnnot(&page, FPDF_ANNOT_SQUARE)
+            .unwrap();
+        drop(page);
+
+        let annotations = document.all_annotations().unwrap();
+        assert_eq!(annotations.len(), 3);
+        assert_eq!(
+            annotations.len() as i64,
+            document.total_annotation_count().unwrap()
+        );
+        let pages: Vec<i32> = annotations.iter().map(|a| a.page_index).collect();
+        assert_eq!(pages, vec![0, 2, 2]);
+        assert_eq!(annotations[0].subtype, FPDF_ANNOT_TEXT);
+        assert_eq!(annotations[0].contents.as_deref(), Some("Note on page 0"));
+        assert_eq!(annotations[0].rect.width(), 20.0);
+        assert_eq!(annotations[1].contents.as_deref(), Some("Note on page 2"));
+        assert_eq!(annotations[2].subtype, FPDF_ANNOT_SQUARE);
+        assert!(annotations[2].contents.is_none());
+    }
+
+    #[test]
+    fn test_search_with_context() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let hits = document
+            .search_with_context("groningen", PdfiumSearchFlags::empty(), 5)
+            .unwrap();
+        assert!(!hits.is_empty());
+        let hit = &hits[0];
+        assert_eq!(hit.page_index, 0);
+        assert_eq!(hit.text, "Groningen");
+        assert!(hit.snippet.contains("e to Groningen!"), "{}", hit.snippet);
+        let text = document.page(0).unwrap().text().unwrap();
+        assert_eq!(text.extract(hit.char_index, 9), "Groningen");
+
+        let hits = document
+            .search_with_context("groningen", PdfiumSearchFlags::empty(), 0)
+            .unwrap();
+        assert_eq!(hits[0].snippet, "Groningen");
+        assert!(document
+            .search_with_context("no such text", PdfiumSearchFlags::empty(), 10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_thumbnails() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        for concurrency in [1, 2] {
+            let thumbnails = document.thumbnails(64, concurrency).unwrap();
+            assert_eq!(thumbnails.len(), 2);
+            for thumbnail in thumbnails {
+                assert!(thumbnail.width() <= 64 && thumbnail.height() <= 64);
+                assert_eq!(thumbnail.width().max(thumbnail.height()), 64);
+            }
+        }
+        assert!(document.thumbnails(0, 1).is_err());
+    }
+
+    #[test]
+    fn test_from_images() {
+        let images = [
+            DynamicImage::ImageRgb8(image::RgbImage::from_pixel(
+                200,
+                100,
+                image::Rgb([255, 0, 0]),
+            )),
+            DynamicImage::ImageRgba8(image::RgbaImage::from_pixel(
+                50,
+                80,
+                image::Rgba([0, 0, 255, 128]),
+            )),
+        ];
+        let document = PdfiumDocument::from_images(&images, None).unwrap();
+        assert_eq!(document.page_count(), 2);
+        assert_eq!(document.page_size(0).unwrap(), (200.0, 100.0));
+        assert_eq!(document.page_size(1).unwrap(), (50.0, 80.0));
+        for page in document.pages() {
+            let page = page.unwrap();
+            assert_eq!(page.object_count(), 1);
+            assert_eq!(page.object(0).unwrap().get_type(), ObjectType::Image);
+        }
+
+        let document = PdfiumDocument::from_images(&images, Some((100.0, 100.0))).unwrap();
+        assert_eq!(document.page_size(1).unwrap(), (100.0, 100.0));
+        let page = document.page(0).unwrap();
+        let (mut left, mut bottom, mut right, mut top) = (0.0, 0.0, 0.0, 0.0);
+        page.object(0)
+            .unwrap()
+            .get_bounds(&mut left, &mut bottom, &mut right, &mut top)
+            .unwrap();
+        assert_eq!((left, bottom, right, top), (0.0, 25.0, 100.0, 75.0));
+
+        assert!(PdfiumDocument::from_images(&images, Some((0.0, 100.0))).is_err());
+    }
+
+    #[test]
+    fn test_replace_page() {
+        let document = PdfiumDocument::new().unwrap();
+        for (index, width) in [100.0, 200.0, 300.0].into_iter().enumerate() {
+            lib()
+                .FPDFPage_New(&document, index as i32, width, 100.0)
+                .unwrap();
+        }
+        let src_doc = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+
+        document.replace_page(1, &src_doc, 0).unwrap();
+        assert_eq!(document.page_count(), 3);
+        assert_eq!(document.page_size(0).unwrap().0, 100.0);
+        assert_eq!(
+            document.page_size(1).unwrap(),
+            src_doc.page_size(0).unwrap()
+        );
+        assert_eq!(document.page_size(2).unwrap().0, 300.0);
+
+        let config = PdfiumRenderConfig::new().with_width(100);
+        let expected = src_doc.page(0).unwrap().render(&config).unwrap();
+        let replaced = document.page(1).unwrap().render(&config).unwrap();
+        assert_eq!(replaced.as_raw_bytes(), expected.as_raw_bytes());
+
+        assert!(document.replace_page(3, &src_doc, 0).is_err());
+        assert!(document
+            .replace_page(0, &src_doc, src_doc.page_count())
+            .is_err());
+        assert_eq!(document.page_count(), 3);
+    }
+
+    #[test]
+    fn test_insert_blank_page_like() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page_count = document.page_count();
+        let template_size = document.page_size(0).unwrap();
+        let rotation = lib().FPDFPage_GetRotation(&document.page(0).unwrap());
+
+        let page = document.insert_blank_page_like(0, page_count).unwrap();
+        assert_eq!(document.page_count(), page_count + 1);
+        assert_eq!(page.object_count(), 0);
+        assert_eq!(lib().FPDFPage_GetRotation(&page), rotation);
+        let size = document.page_size(page_count).unwrap();
+        assert!((size.0 - template_size.0).abs() < 0.01);
+        assert!((size.1 - template_size.1).abs() < 0.01);
+
+        assert!(document.insert_blank_page_like(page_count + 1, 0).is_err());
+        assert!(document.insert_blank_page_like(0, page_count + 2).is_err());
+    }
+
+    #[test]
+    fn test_page_sizes() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let sizes = document.page_sizes().unwrap();
+        assert_eq!(sizes.len(), document.page_count() as usize);
+        for (index, size) in sizes.iter().enumerate() {
+            assert_eq!(*size, document.page_size(index as i32).unwrap());
+            let media = document
+                .page(index as i32)
+                .unwrap()
+                .boundaries()
+                .media()
+                .unwrap();
+            assert!((size.0 - media.width()).abs() < 0.01);
+        }
+        let ratios = document.page_aspect_ratios().unwrap();
+        assert_eq!(ratios.len(), sizes.len());
+        assert!((ratios[0] - sizes[0].0 / sizes[0].1).abs() < f32::EPSILON);
+        assert!(document.page_size(document.page_count()).is_err());
+    }
+
     #[test]
     fn test_doc_save() {
         let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
@@ -346,4 +1624,487 @@ mod tests {
         let page_count = document.page_count();
         assert_eq!(page_count, 2);
     }
+
+    #[test]
+    fn test_text_in_range() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let range = PdfiumPageSelection::parse("1").unwrap();
+        let texts = document.text_in_range(&range).unwrap();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(texts[0].0, 0);
+        assert_eq!(texts[0].1, document.page(0).unwrap().text().unwrap().full());
+
+        let range = PdfiumPageSelection::parse("2-3").unwrap();
+        assert!(document.text_in_range(&range).is_err());
+    }
+
+    #[test]
+    fn test_pages_containing() {
+        let document = PdfiumDocument::new().unwrap();
+        let font = CString::new("Helvetica").unwrap();
+        for (index, content) in ["Apple pie", "Banana bread", "Crumble with apples"]
+            .iter()
+            .enumerate()
+        {
+            let page = lib()
+                .FPDFPage_New(&document, index as i32, 200.0, 200.0)
+                .unwrap();
+            let text = lib()
+                .FPDFPageObj_NewTextObj(&document, &font, 12.0)
+                .unwrap();
+            lib().FPDFText_SetText(&text, content).unwrap();
+            text.transform(1.0, 0.0, 0.0, 1.0, 20.0, 100.0);
+            page.insert_object_at(text, 0).unwrap();
+        }
+        let bytes = document.save_to_bytes(None).unwrap();
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+
+        let pages = document.pages_containing("apple", PdfiumSearchFlags::empty());
+        assert_eq!(pages.unwrap(), vec![0, 2]);
+        let pages = document.pages_containing("apple", PdfiumSearchFlags::MATCH_CASE);
+        assert_eq!(pages.unwrap(), vec![2]);
+        let pages = document.pages_containing("cherry", PdfiumSearchFlags::empty());
+        assert!(pages.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pages_par_iter() {
+        let bytes: Arc<[u8]> = std::fs::read("resources/groningen.pdf").unwrap().into();
+
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let serial: String = document
+            .pages()
+            .map(|page| page.unwrap().text().unwrap().full())
+            .collect();
+
+        let parallel =
+            PdfiumDocument::pages_par_iter(bytes, None, 4, |_index, page| Ok(page.text()?.full()))
+                .unwrap();
+
+        assert_eq!(parallel.len(), 2);
+        assert_eq!(parallel.concat(), serial);
+    }
+
+    #[test]
+    fn test_new_from_file_range() {
+        let bytes = std::fs::read("resources/linearized.pdf").unwrap();
+
+        // Only the first page section (up to /E in the linearization dictionary) is
+        // available, the remainder of the reader is zeroed out.
+        let first_page_end = 830;
+        let mut partial = bytes.clone();
+        partial[first_page_end..].fill(0);
+
+        let document =
+            PdfiumDocument::new_from_file_range(Cursor::new(partial), first_page_end as u64, None)
+                .unwrap();
+        assert!(document.availability().unwrap().is_linearized());
+        assert_eq!(document.page_count(), 2);
+
+        let first = document.first_available_page();
+        assert_eq!(first, 0);
+        assert!(document.is_page_available(first).unwrap());
+        assert!(!document.is_page_available(1).unwrap());
+
+        let config = crate::PdfiumRenderConfig::new().with_width(100);
+        let bitmap = document.page(first).unwrap().render(&config).unwrap();
+        assert_eq!(bitmap.width(), 100);
+
+        // Nothing available yet
+        let document = PdfiumDocument::new_from_file_range(Cursor::new(bytes), 0, None);
+        assert!(matches!(document, Err(PdfiumError::NotFound)));
+    }
+
+    #[test]
+    fn test_images_per_page() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let config = PdfiumRenderConfig::new().with_width(800);
+        let images: Vec<_> = document
+            .images_per_page(&config)
+            .collect::<PdfiumResult<_>>()
+            .unwrap();
+        assert_eq!(images.len(), 2);
+        for image in images {
+            assert_eq!(image.width(), 800);
+            assert!(image.height() > 800);
+        }
+    }
+
+    #[test]
+    fn test_first_and_last_page() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        assert_eq!(
+            document.first_page().unwrap().text().unwrap().full(),
+            document.page(0).unwrap().text().unwrap().full()
+        );
+        assert_eq!(
+            document.last_page().unwrap().text().unwrap().full(),
+            document.page(1).unwrap().text().unwrap().full()
+        );
+
+        let document = PdfiumDocument::new().unwrap();
+        assert!(matches!(document.first_page(), Err(PdfiumError::PageError)));
+        assert!(matches!(document.last_page(), Err(PdfiumError::PageError)));
+    }
+
+    #[test]
+    fn test_remove_pages() {
+        let document = PdfiumDocument::new().unwrap();
+        for i in 0..5 {
+            lib()
+                .FPDFPage_New(&document, i, 100.0 * (i + 1) as f64, 100.0)
+                .unwrap();
+        }
+        assert_eq!(document.page_count(), 5);
+
+        let range = PdfiumPageSelection::parse("2,4").unwrap();
+        document.remove_pages(&range).unwrap();
+        assert_eq!(document.page_count(), 3);
+
+        let widths: Vec<f32> = document
+            .pages()
+            .map(|page| lib().FPDF_GetPageWidthF(&page.unwrap()))
+            .collect();
+        assert_eq!(widths, vec![100.0, 300.0, 500.0]);
+
+        let range = PdfiumPageSelection::parse("1,4").unwrap();
+        assert!(document.remove_pages(&range).is_err());
+        assert_eq!(document.page_count(), 3);
+
+        let range = PdfiumPageSelection::parse("1-2000000000").unwrap();
+        assert!(document.remove_pages(&range).is_err());
+        assert_eq!(document.page_count(), 3);
+
+        let range = PdfiumPageSelection::parse("1-2,2").unwrap();
+        document.remove_pages(&range).unwrap();
+        assert_eq!(document.page_count(), 1);
+    }
+
+    #[test]
+    fn test_bake_rotations() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let render = |page: &PdfiumPage| {
+            let (width, height) = (
+                lib().FPDF_GetPageWidthF(page) / 4.0,
+                lib().FPDF_GetPageHeightF(page) / 4.0,
+            );
+            let config = PdfiumRenderConfig::new()
+                .with_size(width.round() as i32, height.round() as i32)
+                .with_scale(0.25)
+                .with_background(PdfiumColor::WHITE);
+            page.render(&config).unwrap().as_luma_image().unwrap()
+        };
+
+        let page = document.page(0).unwrap();
+        lib().FPDFPage_SetRotation(&page, 1);
+        let before = render(&page);
+
+        document.bake_rotations().unwrap();
+        let page = document.page(0).unwrap();
+        assert_eq!(lib().FPDFPage_GetRotation(&page), 0);
+        let after = render(&page);
+
+        assert_eq!(before.dimensions(), after.dimensions());
+        let diff: u64 = before
+            .pixels()
+            .zip(after.pixels())
+            .map(|(a, b)| a.0[0].abs_diff(b.0[0]) as u64)
+            .sum();
+        assert!(diff < before.len() as u64, "average difference too large");
+    }
+
+    #[test]
+    fn test_set_all_crop_boxes() {
+        let document = PdfiumDocument::new().unwrap();
+        lib().FPDFPage_New(&document, 0, 300.0, 400.0).unwrap();
+        lib().FPDFPage_New(&document, 1, 300.0, 400.0).unwrap();
+
+        let crop = PdfiumRect::new_from_lbrt((20.0, 30.0, 280.0, 370.0));
+        document.set_all_crop_boxes(crop).unwrap();
+        let too_large = PdfiumRect::new_from_lbrt((-10.0, 0.0, 300.0, 400.0));
+        assert!(document.set_all_trim_boxes(too_large).is_err());
+
+        let bytes = document.save_to_bytes(None).unwrap();
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        for page in document.pages() {
+            let page = page.unwrap();
+            let boundaries = page.boundaries();
+            let rect = boundaries.crop().unwrap();
+            assert_eq!(
+                (rect.left, rect.bottom, rect.right, rect.top),
+                (20.0, 30.0, 280.0, 370.0)
+            );
+            assert!(boundaries.trim().is_err());
+        }
+    }
+
+    #[test]
+    fn test_set_all_crop_boxes_inherited_media_box() {
+        let document =
+            PdfiumDocument::new_from_path("resources/inherited_mediabox.pdf", None).unwrap();
+        let too_large = PdfiumRect::new_from_lbrt((0.0, 0.0, 300.0, 500.0));
+        assert!(document.set_all_crop_boxes(too_large).is_err());
+        let crop = PdfiumRect::new_from_lbrt((20.0, 30.0, 280.0, 370.0));
+        document.set_all_crop_boxes(crop).unwrap();
+
+        for page in document.pages() {
+            let rect = page.unwrap().boundaries().crop().unwrap();
+            assert_eq!(
+                (rect.left, rect.bottom, rect.right, rect.top),
+                (20.0, 30.0, 280.0, 370.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_for_each_page_text() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        assert!(document.page_count() > 1);
+
+        let mut calls = Vec::new();
+        document
+            .for_each_page_text(|index, text| {
+                calls.push((index, text.to_string()));
+                ControlFlow::Break(())
+            })
+            .unwrap();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, 0);
+        assert_eq!(calls[0].1, document.page(0).unwrap().text().unwrap().full());
+
+        let mut count = 0;
+        document
+            .for_each_page_text(|_, _| {
+                count += 1;
+                ControlFlow::Continue(())
+            })
+            .unwrap();
+        assert_eq!(count, document.page_count());
+    }
+
+    #[test]
+    fn test_on_page_load() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let loaded = Rc::new(RefCell::new(Vec::new()));
+        let sink = loaded.clone();
+        document.on_page_load(move |index, _| sink.borrow_mut().push(index));
+
+        for index in [1, 0, 1] {
+            document.page(index).unwrap();
+        }
+        assert!(document.page(1000).is_err());
+        assert_eq!(*loaded.borrow(), [1, 0, 1]);
+
+        document.clear_page_load_hook();
+        document.page(0).unwrap();
+        assert_eq!(loaded.borrow().len(), 3);
+    }
+
+    #[test]
+    fn test_on_page_load_reentrant() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let loaded = Rc::new(RefCell::new(Vec::new()));
+        let sink = loaded.clone();
+        let inner = document.clone();
+        document.on_page_load(move |index, _| {
+            // Loading a page from within the hook does not call the hook again
+            assert!(inner.page(index + 1).is_ok());
+            sink.borrow_mut().push(index);
+        });
+
+        document.page(0).unwrap();
+        assert_eq!(*loaded.borrow(), [0]);
+        document.clear_page_load_hook();
+    }
+
+    #[test]
+    fn test_duplicate() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let copy = document.duplicate().unwrap();
+        assert_eq!(copy.page_count(), document.page_count());
+
+        let rotation = lib().FPDFPage_GetRotation(&document.page(0).unwrap());
+        let page = copy.page(0).unwrap();
+        lib().FPDFPage_SetRotation(&page, (rotation + 1) % 4);
+        assert_eq!(lib().FPDFPage_GetRotation(&page), (rotation + 1) % 4);
+
+        assert_eq!(
+            lib().FPDFPage_GetRotation(&document.page(0).unwrap()),
+            rotation
+        );
+    }
+
+    #[test]
+    fn test_duplicate_encrypted() {
+        assert!(matches!(
+            PdfiumDocument::new_from_path("resources/encrypted.pdf", None),
+            Err(PdfiumError::PasswordError)
+        ));
+        let document =
+            PdfiumDocument::new_from_path("resources/encrypted.pdf", Some("secret")).unwrap();
+        let copy = document.duplicate().unwrap();
+        assert_eq!(copy.page_count(), 1);
+        let config = PdfiumRenderConfig::new().with_width(20);
+        let bitmap = copy.page(0).unwrap().render(&config).unwrap();
+        assert!(!bitmap.is_mostly_blank(1.0));
+    }
+
+    #[test]
+    fn test_remove_all_javascript() {
+        let document = PdfiumDocument::new_from_path("resources/javascript.pdf", None).unwrap();
+        assert_eq!(document.javascript_action_count(), 1);
+
+        assert_eq!(document.remove_all_javascript().unwrap(), 1);
+        assert_eq!(document.remove_all_javascript().unwrap(), 0);
+
+        let bytes = document.save_to_bytes(None).unwrap();
+        let saved = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        assert_eq!(saved.remove_all_javascript().unwrap(), 0);
+        let key = CString::new("AA").unwrap();
+        let page = saved.page(0).unwrap();
+        for index in 0..page.annotation_count() {
+            let annotation = page.annotation(index).unwrap();
+            assert_ne!(
+                lib().FPDFAnnot_GetValueType(&annotation, &key),
+                FPDF_OBJECT_DICTIONARY
+            );
+        }
+
+        // Document-level JavaScript cannot be removed in place
+        assert_eq!(saved.javascript_action_count(), 1);
+    }
+
+    #[test]
+    fn test_downsample_images() {
+        let original = fs::read("resources/pg1342-images-3.pdf").unwrap();
+        let document =
+            PdfiumDocument::new_from_path("resources/pg1342-images-3.pdf", None).unwrap();
+        let modified = document.downsample_images(36.0, 50).unwrap();
+        assert!(modified > 0);
+
+        let bytes = document.save_to_bytes(None).unwrap();
+        assert!(bytes.len() < original.len());
+
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        let config = PdfiumRenderConfig::new().with_width(200);
+        let bitmap = document.page(0).unwrap().render(&config).unwrap();
+        assert_eq!(bitmap.width(), 200);
+    }
+
+    #[test]
+    fn test_downsample_images_skips_masks() {
+        let document = PdfiumDocument::new_from_path("resources/masked_images.pdf", None).unwrap();
+        // Only the plain image is downsampled, not the stencil mask or the masked image
+        assert_eq!(document.downsample_images(72.0, 80).unwrap(), 1);
+
+        let page = document.page(0).unwrap();
+        let sizes: Vec<(u32, u32)> = page
+            .objects()
+            .map(|object| {
+                let (mut width, mut height) = (0, 0);
+                lib()
+                    .FPDFImageObj_GetImagePixelSize(&object.unwrap(), &mut width, &mut height)
+                    .unwrap();
+                (width, height)
+            })
+            .collect();
+        assert_eq!(sizes, [(50, 50), (200, 200), (200, 200)]);
+    }
+
+    #[test]
+    fn test_save_incremental_preserves_signature() {
+        let original = fs::read("resources/signed.pdf").unwrap();
+        let document = PdfiumDocument::new_from_path("resources/signed.pdf", None).unwrap();
+        assert_eq!(document.signature_count(), 1);
+        let signature = document.signature(0).unwrap();
+        let byte_range = signature.byte_range();
+        let contents = signature.contents();
+        assert_eq!(byte_range.len(), 4);
+        assert!(!contents.is_empty());
+
+        lib().FPDFPage_SetRotation(&document.page(0).unwrap(), 1);
+        let bytes = document
+            .save_incremental_to_writer(Cursor::new(Vec::new()))
+            .unwrap()
+            .into_inner();
+        assert!(bytes.len() > original.len());
+        assert_eq!(&bytes[..original.len()], original.as_slice());
+
+        let saved = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        assert_eq!(lib().FPDFPage_GetRotation(&saved.page(0).unwrap()), 1);
+        let signature = saved.signature(0).unwrap();
+        assert_eq!(signature.byte_range(), byte_range);
+        assert_eq!(signature.contents(), contents);
+    }
+
+    #[test]
+    fn test_total_counts() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let (mut objects, mut annotations) = (0, 0);
+        for page in document.pages() {
+            let page = page.unwrap();
+            objects += page.object_count() as i64;
+            annotations += page.annotation_count() as i64;
+        }
+        assert!(objects > 0);
+        assert_eq!(document.total_object_count().unwrap(), objects);
+        assert_eq!(document.total_annotation_count().unwrap(), annotations);
+
+        let document = PdfiumDocument::new_from_path("resources/form.pdf", None).unwrap();
+        assert_eq!(document.total_annotation_count().unwrap(), 8);
+    }
+
+    #[test]
+    fn test_bookmarks() {
+        let document = PdfiumDocument::new_from_path("resources/outline.pdf", None).unwrap();
+        let chapter = document.first_bookmark().unwrap();
+        assert_eq!(chapter.title(), "Chapter 1");
+        assert_eq!(chapter.page_index(), Some(0));
+        let section = chapter.first_child().unwrap();
+        assert_eq!(section.title(), "Section 1.1");
+        let section = section.next_sibling().unwrap();
+        assert_eq!(section.page_index(), Some(2));
+        assert!(section.next_sibling().is_none());
+        let appendix = chapter.next_sibling().unwrap();
+        assert_eq!(appendix.page_index(), None);
+        assert!(appendix.first_child().is_none());
+
+        let document = PdfiumDocument::new().unwrap();
+        assert!(document.first_bookmark().is_none());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_outline_json() {
+        use serde_json::{json, Value};
+
+        let document = PdfiumDocument::new_from_path("resources/outline.pdf", None).unwrap();
+        let outline: Value = serde_json::from_str(&document.outline_json().unwrap()).unwrap();
+        assert_eq!(
+            outline,
+            json!([
+                {"title": "Chapter 1", "page": 0, "children": [
+                    {"title": "Section 1.1", "page": 1, "children": []},
+                    {"title": "Section \"1.2\"", "page": 2, "children": []},
+                ]},
+                {"title": "Appendix", "page": null, "children": []},
+            ])
+        );
+
+        // Circular references are followed only once
+        let document = PdfiumDocument::new_from_path("resources/outline_cycle.pdf", None).unwrap();
+        let outline: Value = serde_json::from_str(&document.outline_json().unwrap()).unwrap();
+        assert_eq!(
+            outline,
+            json!([
+                {"title": "Chapter 1", "page": 0, "children": [
+                    {"title": "Section 1.1", "page": null, "children": []},
+                ]},
+                {"title": "Appendix", "page": 2, "children": []},
+            ])
+        );
+
+        let empty = PdfiumDocument::new().unwrap().outline_json().unwrap();
+        assert_eq!(serde_json::from_str::<Value>(&empty).unwrap(), json!([]));
+    }
 }
@@ -19,7 +19,7 @@
 
 use std::{
     ffi::{c_int, c_uchar, c_ulong, c_void},
-    io::{Read, Seek, SeekFrom},
+    io::{self, Read, Seek, SeekFrom},
     ptr::null_mut,
     slice,
 };
@@ -31,6 +31,7 @@ use crate::pdfium_types::FPDF_FILEACCESS;
 pub struct PdfiumReader {
     file_access_ptr: FPDF_FILEACCESS,
     reader: Box<dyn PdfiumReaderExt>, // Type-erased equivalent of <R: Read + Seek>
+    error: Option<io::Error>,         // First error returned by `reader`
 }
 
 impl PdfiumReader {
@@ -46,6 +47,7 @@ impl PdfiumReader {
         let mut pdfium_reader = Box::new(PdfiumReader {
             file_access_ptr: file_access,
             reader: Box::new(reader),
+            error: None,
         });
 
         // Store a pointer to this PdfiumReader instance in m_Param. This pointer
@@ -55,6 +57,13 @@ impl PdfiumReader {
         pdfium_reader.as_mut().file_access_ptr.m_Param = pdfium_reader_ptr as *mut c_void;
         pdfium_reader
     }
+
+    /// Returns the first IO error the underlying reader returned, if any, and clears it.
+    ///
+    /// PDFium only learns that a read failed, so the original error is kept here.
+    pub(crate) fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
 }
 
 /// Converts a mutable reference to PdfiumReader into a raw pointer to FPDF_FILEACCESS
@@ -81,7 +90,9 @@ impl<R: Read + Seek> PdfiumReaderExt for R {}
 /// - `size`: Number of bytes to read
 ///
 /// # Returns
-/// The number of bytes actually read, or 0 on error.
+/// The number of bytes read, or 0 on error. PDFium expects the block to be filled
+/// completely, so a short read is an error as well. The first error is stored in the
+/// PdfiumReader so it can be reported to the caller.
 extern "C" fn read_callback(
     param: *mut c_void,
     position: c_ulong,
@@ -94,12 +105,15 @@ extern "C" fn read_callback(
 
     #[allow(clippy::unnecessary_cast)]
     // c_ulong isn't guaranteed to be u64 on all platforms
-    let result = match reader.seek(SeekFrom::Start(position as u64)) {
-        Ok(_) => reader
-            .read(unsafe { slice::from_raw_parts_mut(buf, size as usize) })
-            .unwrap_or(0),
-        Err(_) => 0,
-    };
+    let result = reader
+        .seek(SeekFrom::Start(position as u64))
+        .and_then(|_| reader.read_exact(unsafe { slice::from_raw_parts_mut(buf, size as usize) }));
 
-    result as c_int
+    match result {
+        Ok(()) => size as c_int,
+        Err(err) => {
+            pdfium_reader.error.get_or_insert(err);
+            0
+        }
+    }
 }
@@ -0,0 +1,123 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{page::object::ObjectType, PdfiumDocument, PdfiumPage, PdfiumPageObject, PdfiumResult};
+
+/// Content statistics of a document, as returned by [`PdfiumDocument::content_stats`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PdfiumContentStats {
+    /// Number of pages
+    pub page_count: i32,
+
+    /// Number of pages with text that is not only white space
+    pub pages_with_text: i32,
+
+    /// Number of pages with at least one image, including images inside form objects
+    pub pages_with_images: i32,
+
+    /// Number of pages with at least one image but no text, typically scanned pages
+    pub image_only_pages: i32,
+
+    /// Number of page objects on all pages, not counting objects inside form objects
+    pub total_objects: i32,
+
+    /// Number of annotations on all pages
+    pub total_annotations: i32,
+}
+
+impl PdfiumDocument {
+    /// Collects [`PdfiumContentStats`] of this [`PdfiumDocument`] in a single pass over its
+    /// pages.
+    ///
+    /// Useful to route documents, e.g. to OCR when most pages are image only, or to text
+    /// extraction when most pages have text. The text page is only loaded for pages that
+    /// contain text (or form) objects, see [`PdfiumPage::is_empty_text`].
+    pub fn content_stats(&self) -> PdfiumResult<PdfiumContentStats> {
+        let mut stats = PdfiumContentStats {
+            page_count: self.page_count(),
+            ..Default::default()
+        };
+        for page in self.pages() {
+            let page = page?;
+            let has_text = has_text(&page)?;
+            let has_images = page
+                .objects()
+                .filter_map(Result::ok)
+                .any(|object| contains_image(&object));
+            stats.pages_with_text += has_text as i32;
+            stats.pages_with_images += has_images as i32;
+            stats.image_only_pages += (has_images && !has_text) as i32;
+            stats.total_objects += page.object_count();
+            stats.total_annotations += page.annotation_count();
+        }
+        Ok(stats)
+    }
+}
+
+/// Returns `true` if `page` has text other than white space.
+fn has_text(page: &PdfiumPage) -> PdfiumResult<bool> {
+    if page.is_empty_text()? {
+        return Ok(false);
+    }
+    Ok(page.text()?.full().chars().any(|c| !c.is_whitespace()))
+}
+
+/// Returns `true` if `object` is an image, or a form object containing an image.
+fn contains_image(object: &PdfiumPageObject) -> bool {
+    match object.get_type() {
+        ObjectType::Image => true,
+        ObjectType::Form => (0..object.form_object_count())
+            .filter_map(|index| object.form_object(index).ok())
+            .any(|object| contains_image(&object)),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{DynamicImage, RgbImage};
+
+    use crate::*;
+
+    #[test]
+    fn test_content_stats_scanned() {
+        let images = vec![
+            DynamicImage::ImageRgb8(RgbImage::from_pixel(200, 300, image::Rgb([240, 240, 240]))),
+            DynamicImage::ImageRgb8(RgbImage::from_pixel(300, 200, image::Rgb([10, 10, 10]))),
+        ];
+        let document = PdfiumDocument::from_images(&images, None).unwrap();
+        let stats = document.content_stats().unwrap();
+        assert_eq!(stats.page_count, 2);
+        assert_eq!(stats.pages_with_text, 0);
+        assert_eq!(stats.pages_with_images, 2);
+        assert_eq!(stats.image_only_pages, 2);
+        assert_eq!(stats.total_objects, 2);
+        assert_eq!(stats.total_annotations, 0);
+    }
+
+    #[test]
+    fn test_content_stats_text() {
+        let document = PdfiumDocument::new_from_path("resources/chapter1.pdf", None).unwrap();
+        let stats = document.content_stats().unwrap();
+        assert_eq!(stats.page_count, document.page_count());
+        assert!(stats.pages_with_text * 2 > stats.page_count);
+        assert!(stats.image_only_pages < stats.pages_with_text);
+        assert!(stats.total_objects > 0);
+    }
+}
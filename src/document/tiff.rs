@@ -0,0 +1,137 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    fs::File,
+    io::{BufWriter, Seek, Write},
+    path::Path,
+};
+
+use image::RgbImage;
+use tiff::{
+    encoder::{colortype::RGB8, Rational, TiffEncoder},
+    tags::ResolutionUnit,
+};
+
+use crate::{
+    error::PdfiumError, PdfiumBitmapFormat, PdfiumColor, PdfiumDocument, PdfiumRenderFlags,
+    PdfiumResult,
+};
+
+impl PdfiumDocument {
+    /// Renders every page of this [`PdfiumDocument`] at `dpi` and saves them as the frames of
+    /// a single multipage TIFF file at `path`.
+    ///
+    /// Pages are rendered with annotations on a white background and stored as uncompressed
+    /// 8-bit RGB, with their resolution set to `dpi`. Only one page is held in memory at a
+    /// time. As TIFF uses 32-bit offsets, the file cannot exceed 4 GiB.
+    ///
+    /// Requires the `tiff` feature.
+    pub fn save_as_multipage_tiff(&self, path: impl AsRef<Path>, dpi: f32) -> PdfiumResult<()> {
+        if dpi <= 0.0 || !dpi.is_finite() {
+            return Err(PdfiumError::InvalidConfiguration(
+                "DPI must be positive".to_string(),
+            ));
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        let mut tiff = TiffEncoder::new(&mut writer).or(Err(PdfiumError::ImageError))?;
+        for page in self.pages() {
+            let bitmap = page?.render_at_dpi(
+                dpi,
+                PdfiumBitmapFormat::Bgra,
+                Some(PdfiumColor::WHITE),
+                PdfiumRenderFlags::ANNOT,
+            )?;
+            let image = image::DynamicImage::ImageRgba8(bitmap.as_rgba_image()?).into_rgb8();
+            write_frame(&mut tiff, &image, dpi)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Appends `image` to the multipage TIFF written by `tiff`, with a resolution of `dpi`.
+fn write_frame<W: Write + Seek>(
+    tiff: &mut TiffEncoder<W>,
+    image: &RgbImage,
+    dpi: f32,
+) -> PdfiumResult<()> {
+    let mut frame = tiff
+        .new_image::<RGB8>(image.width(), image.height())
+        .or(Err(PdfiumError::ImageError))?;
+    let resolution = Rational {
+        n: (dpi * 100.0).round() as u32,
+        d: 100,
+    };
+    frame.resolution(ResolutionUnit::Inch, resolution);
+    frame
+        .write_data(image.as_raw())
+        .or(Err(PdfiumError::ImageError))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, io::Cursor};
+
+    use tiff::decoder::Decoder;
+
+    use super::*;
+
+    /// Returns the (width, height) of each frame of the TIFF in `bytes`.
+    fn frames(bytes: &[u8]) -> Vec<(u32, u32)> {
+        let mut decoder = Decoder::new(Cursor::new(bytes)).unwrap();
+        let mut frames = vec![decoder.dimensions().unwrap()];
+        while decoder.more_images() {
+            decoder.next_image().unwrap();
+            frames.push(decoder.dimensions().unwrap());
+        }
+        frames
+    }
+
+    #[test]
+    fn test_write_frame() {
+        let mut bytes = Cursor::new(Vec::new());
+        let mut tiff = TiffEncoder::new(&mut bytes).unwrap();
+        write_frame(&mut tiff, &RgbImage::new(3, 5), 72.0).unwrap();
+        write_frame(&mut tiff, &RgbImage::new(4, 2), 150.0).unwrap();
+        assert_eq!(frames(bytes.get_ref()), [(3, 5), (4, 2)]);
+    }
+
+    #[test]
+    fn test_save_as_multipage_tiff() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        while document.page_count() > 2 {
+            document.delete_page(document.page_count() - 1).unwrap();
+        }
+        assert_eq!(document.page_count(), 2);
+
+        let path = std::env::temp_dir().join("pdfium-rs-multipage.tiff");
+        document.save_as_multipage_tiff(&path, 18.0).unwrap();
+        let bytes = fs::read(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let frames = frames(&bytes);
+        assert_eq!(frames.len(), 2);
+        let (width, height) = document.page_size(0).unwrap();
+        assert_eq!(
+            frames[0],
+            ((width / 4.0).round() as u32, (height / 4.0).round() as u32)
+        );
+    }
+}
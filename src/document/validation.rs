@@ -0,0 +1,139 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use crate::{error::PdfiumError, PdfiumDocument, PdfiumRenderConfig, PdfiumResult};
+
+/// Number of page failures after which [`PdfiumDocument::validate`] stops checking.
+const DEFAULT_MAX_FAILURES: usize = 10;
+
+/// Outcome of [`PdfiumDocument::validate`]
+#[derive(Debug)]
+pub struct PdfiumValidationReport {
+    /// Number of pages the document claims to have
+    pub page_count: i32,
+
+    /// Number of pages that were actually checked
+    pub pages_checked: i32,
+
+    /// Pages that could not be loaded, as `(index, error)`
+    pub failed_pages: Vec<(i32, PdfiumError)>,
+
+    /// Error returned while rendering the first page, if any
+    pub render_error: Option<PdfiumError>,
+
+    /// `true` if checking stopped early because too many pages failed
+    pub truncated: bool,
+}
+
+impl PdfiumValidationReport {
+    /// Returns `true` if the document has pages, all of them were checked and no problems
+    /// were found.
+    pub fn is_valid(&self) -> bool {
+        self.page_count > 0
+            && !self.truncated
+            && self.failed_pages.is_empty()
+            && self.render_error.is_none()
+    }
+}
+
+impl PdfiumDocument {
+    /// Performs cheap structural sanity checks on this [`PdfiumDocument`].
+    ///
+    /// Checks that the document has pages, that every page loads and that the first page
+    /// renders at a tiny size. Stops after ten failed pages, see
+    /// [`PdfiumDocument::validate_with_max_failures`].
+    pub fn validate(&self) -> PdfiumResult<PdfiumValidationReport> {
+        self.validate_with_max_failures(DEFAULT_MAX_FAILURES)
+    }
+
+    /// Same as [`PdfiumDocument::validate`], but stops after `max_failures` failed pages.
+    ///
+    /// Returns [`PdfiumError::InvalidConfiguration`] if `max_failures` is 0.
+    pub fn validate_with_max_failures(
+        &self,
+        max_failures: usize,
+    ) -> PdfiumResult<PdfiumValidationReport> {
+        if max_failures == 0 {
+            return Err(PdfiumError::InvalidConfiguration(
+                "At least one page failure must be allowed".to_string(),
+            ));
+        }
+        let page_count = self.page_count();
+        let mut report = PdfiumValidationReport {
+            page_count,
+            pages_checked: 0,
+            failed_pages: Vec::new(),
+            render_error: None,
+            truncated: false,
+        };
+        for index in 0..page_count {
+            if report.failed_pages.len() >= max_failures {
+                report.truncated = true;
+                break;
+            }
+            report.pages_checked += 1;
+            match self.page(index) {
+                Ok(page) if index == 0 => {
+                    let config = PdfiumRenderConfig::new().with_width(16);
+                    report.render_error = page.render(&config).err();
+                }
+                Ok(_) => {}
+                Err(error) => report.failed_pages.push((index, error)),
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_good_document() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let report = document.validate().unwrap();
+        assert!(report.is_valid());
+        assert_eq!(report.page_count, 2);
+        assert_eq!(report.pages_checked, 2);
+        assert!(!report.truncated);
+    }
+
+    #[test]
+    fn test_validate_unloadable_pages() {
+        let document = PdfiumDocument::new_from_path("resources/broken_pages.pdf", None).unwrap();
+        let report = document.validate().unwrap();
+        assert!(!report.is_valid());
+        assert_eq!(report.page_count, 3);
+        assert!(report.render_error.is_none());
+        let failed: Vec<i32> = report
+            .failed_pages
+            .iter()
+            .map(|(index, _)| *index)
+            .collect();
+        assert_eq!(failed, vec![1, 2]);
+
+        let report = document.validate_with_max_failures(1).unwrap();
+        assert_eq!(report.failed_pages.len(), 1);
+        assert_eq!(report.pages_checked, 2);
+        assert!(report.truncated);
+        assert!(!report.is_valid());
+
+        assert!(document.validate_with_max_failures(0).is_err());
+    }
+}
@@ -21,6 +21,7 @@ use std::{
     error::Error,
     ffi::NulError,
     fmt::{Display, Formatter},
+    path::PathBuf,
 };
 
 /// Alias for `Result<T, PdfiumError>`
@@ -66,7 +67,7 @@ pub enum PdfiumError {
     InvokationFailed,
 
     /// Error loading or initializing the PDFium library
-    LibraryError(String),
+    LibraryError(PdfiumLibraryError),
 
     /// An error occurred in the image create
     ImageError,
@@ -76,6 +77,71 @@ pub enum PdfiumError {
 
     /// An invalid or unclear render configuration was provided
     InvalidConfiguration(String),
+
+    /// The operation did not complete before its deadline
+    Timeout,
+}
+
+/// Reasons why the PDFium dynamic library could not be loaded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfiumLibraryError {
+    /// The library file could not be found at the given path or in the system search paths
+    NotFound(PathBuf),
+
+    /// The library file was found but could not be loaded (e.g. not a valid library, or
+    /// one of its own dependencies is missing)
+    LoadFailed(String),
+
+    /// The library does not export the named function, usually because it is older than
+    /// the PDFium version these bindings were generated for
+    MissingSymbol(String),
+
+    /// The library was built for a different CPU architecture than the application
+    ArchMismatch,
+}
+
+impl PdfiumLibraryError {
+    /// Classifies the error returned by `libloading` when opening the library at `path`.
+    ///
+    /// A "no such file" error for an existing `path` means one of the dependencies of the
+    /// library is missing, which is reported as [`PdfiumLibraryError::LoadFailed`].
+    pub(crate) fn from_load_error(path: PathBuf, error: &libloading::Error) -> Self {
+        let message = error.to_string().replace('\n', "");
+        if message.contains("wrong ELF class") || message.contains("incompatible architecture") {
+            PdfiumLibraryError::ArchMismatch
+        } else if !path.exists()
+            && (message.contains("No such file")
+                || message.contains("no such file")
+                || message.contains("image not found")
+                || message.contains("(os error 126)"))
+        {
+            PdfiumLibraryError::NotFound(path)
+        } else {
+            PdfiumLibraryError::LoadFailed(message)
+        }
+    }
+}
+
+impl Display for PdfiumLibraryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PdfiumLibraryError::NotFound(path) => {
+                write!(f, "PDFium library not found: {}", path.display())
+            }
+            PdfiumLibraryError::LoadFailed(message) => {
+                write!(f, "PDFium library could not be loaded: {message}")
+            }
+            PdfiumLibraryError::MissingSymbol(function) => {
+                write!(
+                    f,
+                    "PDFium library does not export '{function}' (library too old?)"
+                )
+            }
+            PdfiumLibraryError::ArchMismatch => {
+                write!(f, "PDFium library was built for a different architecture")
+            }
+        }
+    }
 }
 
 impl From<std::io::Error> for PdfiumError {
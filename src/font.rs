@@ -39,6 +39,18 @@ impl PdfiumFont {
             })
         }
     }
+
+    /// Wraps a font that remains owned by PDFium (e.g. the font of a text object), so
+    /// it must not be closed when dropped.
+    pub(crate) fn new_from_borrowed_handle(handle: FPDF_FONT) -> PdfiumResult<Self> {
+        if handle.is_null() {
+            Err(PdfiumError::NullHandle)
+        } else {
+            Ok(Self {
+                handle: Handle::new(handle, None),
+            })
+        }
+    }
 }
 
 impl From<&PdfiumFont> for FPDF_FONT {
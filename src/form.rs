@@ -17,15 +17,28 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::rc::Rc;
+
 use crate::{
+    c_api::{guard::PdfiumGuard, utf16::read_utf16},
     error::{PdfiumError, PdfiumResult},
-    pdfium_types::{FormHandle, Handle, FPDF_FORMHANDLE},
+    lib,
+    pdfium_constants::{
+        FPDF_ANNOT_WIDGET, FPDF_FORMFIELD_CHECKBOX, FPDF_FORMFIELD_COMBOBOX,
+        FPDF_FORMFIELD_LISTBOX, FPDF_FORMFIELD_PUSHBUTTON, FPDF_FORMFIELD_RADIOBUTTON,
+        FPDF_FORMFIELD_SIGNATURE, FPDF_FORMFIELD_TEXTFIELD,
+    },
+    pdfium_types::{FormHandle, Handle, FPDF_FORMFILLINFO, FPDF_FORMHANDLE},
+    try_lib, PdfiumAnnotation, PdfiumDocument, PdfiumPage,
 };
 
 /// # Rust interface to FPDF_FORMHANDLE
 #[derive(Debug, Clone)]
 pub struct PdfiumForm {
     handle: FormHandle,
+    #[allow(clippy::redundant_allocation)]
+    form_info: Option<Rc<Box<FPDF_FORMFILLINFO>>>,
+    owner: Option<PdfiumDocument>,
 }
 
 impl PdfiumForm {
@@ -34,10 +47,273 @@ impl PdfiumForm {
             Err(PdfiumError::NullHandle)
         } else {
             Ok(Self {
-                handle: Handle::new(handle, None), // TODO: check close is not needed
+                handle: Handle::new(handle, Some(close_form)),
+                form_info: None,
+                owner: None,
             })
         }
     }
+
+    pub(crate) fn set_owner(&mut self, owner: PdfiumDocument) {
+        self.owner = Some(owner);
+    }
+
+    /// Initializes the form fill environment for `document`.
+    ///
+    /// No callbacks are provided to PDFium, so JavaScript and user interface
+    /// interaction (e.g. alerts) are not supported.
+    pub fn new(document: &PdfiumDocument) -> PdfiumResult<Self> {
+        let lib = try_lib()?;
+        // SAFETY: FPDF_FORMFILLINFO only contains integers, pointers and optional
+        // callbacks, for which all zero bytes are valid (null / None) values.
+        let mut form_info: Box<FPDF_FORMFILLINFO> = Box::new(unsafe { std::mem::zeroed() });
+        form_info.version = 2;
+        let mut form = lib.FPDFDOC_InitFormFillEnvironment(document, &mut form_info)?;
+        form.form_info = Some(Rc::new(form_info));
+        Ok(form)
+    }
+
+    /// Returns the form fields of the widget annotations on `page`.
+    ///
+    /// Widgets that are not part of an interactive form field are skipped. Note that a
+    /// field with multiple widgets (e.g. a radio button group) is returned once for
+    /// each widget.
+    pub fn fields(&self, page: &PdfiumPage) -> Vec<PdfiumFormField> {
+        (0..page.annotation_count())
+            .filter_map(|index| page.annotation(index).ok())
+            .filter(|annotation| annotation.subtype() == FPDF_ANNOT_WIDGET)
+            .filter_map(|annotation| {
+                let field_type = self.field_type(&annotation)?;
+                Some(PdfiumFormField {
+                    name: self.field_name(&annotation).unwrap_or_default(),
+                    field_type,
+                    value: self.field_value(&annotation),
+                    annotation,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the [`PdfiumFormFieldType`] of the form field backing `annotation`, or `None`
+    /// if `annotation` is not a widget of an interactive form field.
+    pub fn field_type(&self, annotation: &PdfiumAnnotation) -> Option<PdfiumFormFieldType> {
+        PdfiumFormFieldType::from_raw(lib().FPDFAnnot_GetFormFieldType(self, annotation))
+    }
+
+    /// Returns the fully qualified name of the form field backing `annotation`.
+    pub fn field_name(&self, annotation: &PdfiumAnnotation) -> Option<String> {
+        read_utf16(|buffer, buflen| {
+            lib().FPDFAnnot_GetFormFieldName(self, annotation, buffer, buflen)
+        })
+    }
+
+    /// Returns the value of the form field backing `annotation`, or `None` if the field
+    /// has no value.
+    pub fn field_value(&self, annotation: &PdfiumAnnotation) -> Option<String> {
+        read_utf16(|buffer, buflen| {
+            lib().FPDFAnnot_GetFormFieldValue(self, annotation, buffer, buflen)
+        })
+        .filter(|value| !value.is_empty())
+    }
+
+    /// Returns `true` if the check box or radio button widget `annotation` is checked.
+    pub fn is_checked(&self, annotation: &PdfiumAnnotation) -> bool {
+        lib().FPDFAnnot_IsChecked(self, annotation).is_ok()
+    }
+
+    /// Checks or unchecks the check box or radio button widget `annotation` on `page`.
+    ///
+    /// The state is changed by simulating a mouse click at the center of the widget, so
+    /// PDFium applies the regular form semantics: checking a radio button unchecks the
+    /// other buttons in its group. Returns an error if the state did not change, e.g.
+    /// when unchecking a radio button of a group that requires one button to be checked.
+    pub fn set_checked(
+        &self,
+        page: &PdfiumPage,
+        annotation: &PdfiumAnnotation,
+        checked: bool,
+    ) -> PdfiumResult<()> {
+        match self.field_type(annotation) {
+            Some(PdfiumFormFieldType::CheckBox) | Some(PdfiumFormFieldType::RadioButton) => {}
+            _ => return Err(PdfiumError::InvokationFailed),
+        }
+        if self.is_checked(annotation) == checked {
+            return Ok(());
+        }
+        self.click(page, annotation)?;
+        if self.is_checked(annotation) == checked {
+            Ok(())
+        } else {
+            Err(PdfiumError::InvokationFailed)
+        }
+    }
+
+    /// Returns the options of the combo box or list box widget `annotation`.
+    ///
+    /// Returns an empty `Vec` for other widget types.
+    pub fn options(&self, annotation: &PdfiumAnnotation) -> Vec<PdfiumFormOption> {
+        let lib = lib();
+        (0..lib.FPDFAnnot_GetOptionCount(self, annotation).max(0))
+            .map(|index| PdfiumFormOption {
+                label: read_utf16(|buffer, buflen| {
+                    lib.FPDFAnnot_GetOptionLabel(self, annotation, index, buffer, buflen)
+                })
+                .unwrap_or_default(),
+                selected: lib
+                    .FPDFAnnot_IsOptionSelected(self, annotation, index)
+                    .is_ok(),
+            })
+            .collect()
+    }
+
+    /// Selects or deselects the option at `index` of the combo box or list box widget
+    /// `annotation` on `page`.
+    ///
+    /// A combo box has at most a single option selected, which cannot be deselected.
+    /// Selecting another option replaces the selection, while deselecting returns an error.
+    pub fn set_option_selected(
+        &self,
+        page: &PdfiumPage,
+        annotation: &PdfiumAnnotation,
+        index: i32,
+        selected: bool,
+    ) -> PdfiumResult<()> {
+        match self.field_type(annotation) {
+            Some(PdfiumFormFieldType::ComboBox) if !selected => {
+                return Err(PdfiumError::InvokationFailed)
+            }
+            Some(PdfiumFormFieldType::ComboBox) | Some(PdfiumFormFieldType::ListBox) => {}
+            _ => return Err(PdfiumError::InvokationFailed),
+        }
+        self.with_page_loaded(page, |lib| {
+            lib.FORM_SetFocusedAnnot(self, annotation)?;
+            lib.FORM_SetIndexSelected(self, page, index, selected as i32)
+        })
+    }
+
+    /// Replaces the text of the text field widget `annotation` on `page` with `text`.
+    ///
+    /// Returns an error if the widget is not a text field, or if the field is read-only.
+    pub fn set_text(
+        &self,
+        page: &PdfiumPage,
+        annotation: &PdfiumAnnotation,
+        text: &str,
+    ) -> PdfiumResult<()> {
+        if self.field_type(annotation) != Some(PdfiumFormFieldType::TextField) {
+            return Err(PdfiumError::InvokationFailed);
+        }
+        self.with_page_loaded(page, |lib| {
+            lib.FORM_SetFocusedAnnot(self, annotation)?;
+            lib.FORM_SelectAllText(self, page)?;
+            lib.FORM_ReplaceSelection(self, page, text);
+            Ok(())
+        })?;
+        match self.field_value(annotation) {
+            Some(value) if value == text => Ok(()),
+            _ => Err(PdfiumError::InvokationFailed),
+        }
+    }
+
+    /// Returns the values of all form fields of `document` as a JSON object, keyed by the
+    /// fully qualified field name.
+    ///
+    /// Text fields are strings, check boxes are booleans, radio button groups are the export
+    /// value of the checked button (or `null` if none is checked) and combo and list boxes are
+    /// arrays of the selected option labels. Push buttons and signature fields have no value
+    /// and are left out. Fields with multiple widgets are listed once.
+    #[cfg(feature = "json")]
+    pub fn to_json(&self, document: &PdfiumDocument) -> PdfiumResult<String> {
+        use crate::json::write_json_string;
+
+        // Field names in document order, with the JSON value of each field
+        let mut values: Vec<(String, String)> = Vec::new();
+        for page in document.pages() {
+            let page = page?;
+            for field in self.fields(&page) {
+                let annotation = &field.annotation;
+                let position = values.iter().position(|(name, _)| *name == field.name);
+                let mut value = String::new();
+                match field.field_type {
+                    PdfiumFormFieldType::TextField => {
+                        write_json_string(&mut value, field.value.as_deref().unwrap_or_default())
+                    }
+                    PdfiumFormFieldType::CheckBox => {
+                        let checked = self.is_checked(annotation)
+                            || position.is_some_and(|index| values[index].1 == "true");
+                        value.push_str(if checked { "true" } else { "false" });
+                    }
+                    PdfiumFormFieldType::RadioButton => {
+                        if self.is_checked(annotation) {
+                            let export = read_utf16(|buffer, buflen| {
+                                lib().FPDFAnnot_GetFormFieldExportValue(
+                                    self, annotation, buffer, buflen,
+                                )
+                            });
+                            write_json_string(&mut value, &export.unwrap_or_default());
+                        } else if let Some(index) = position {
+                            value = values[index].1.clone();
+                        } else {
+                            value.push_str("null");
+                        }
+                    }
+                    PdfiumFormFieldType::ComboBox | PdfiumFormFieldType::ListBox => {
+                        value.push('[');
+                        for option in self.options(annotation) {
+                            if option.selected {
+                                if !value.ends_with('[') {
+                                    value.push(',');
+                                }
+                                write_json_string(&mut value, &option.label);
+                            }
+                        }
+                        value.push(']');
+                    }
+                    PdfiumFormFieldType::PushButton | PdfiumFormFieldType::Signature => continue,
+                }
+                match position {
+                    Some(index) => values[index].1 = value,
+                    None => values.push((field.name, value)),
+                }
+            }
+        }
+
+        let mut json = String::from("{");
+        for (name, value) in values {
+            if !json.ends_with('{') {
+                json.push(',');
+            }
+            write_json_string(&mut json, &name);
+            json.push(':');
+            json.push_str(&value);
+        }
+        json.push('}');
+        Ok(json)
+    }
+
+    /// Simulates a left mouse button click at the center of the widget `annotation`.
+    fn click(&self, page: &PdfiumPage, annotation: &PdfiumAnnotation) -> PdfiumResult<()> {
+        let rect = annotation.rect()?;
+        let x = ((rect.left + rect.right) / 2.0) as f64;
+        let y = ((rect.top + rect.bottom) / 2.0) as f64;
+        self.with_page_loaded(page, |lib| {
+            lib.FORM_OnLButtonDown(self, page, 0, x, y)?;
+            lib.FORM_OnLButtonUp(self, page, 0, x, y)
+        })
+    }
+
+    /// Runs `f` with `page` loaded into the form fill environment, which is required for
+    /// the form interaction functions. Focus is released before the page is unloaded.
+    fn with_page_loaded<F>(&self, page: &PdfiumPage, f: F) -> PdfiumResult<()>
+    where
+        F: FnOnce(&PdfiumGuard) -> PdfiumResult<()>,
+    {
+        let lib = lib();
+        lib.FORM_OnAfterLoadPage(page, self);
+        let result = f(&lib).and_then(|_| lib.FORM_ForceToKillFocus(self));
+        lib.FORM_OnBeforeClosePage(page, self);
+        result
+    }
 }
 
 impl From<&PdfiumForm> for FPDF_FORMHANDLE {
@@ -45,3 +321,213 @@ impl From<&PdfiumForm> for FPDF_FORMHANDLE {
         form.handle.handle()
     }
 }
+
+fn close_form(form: FPDF_FORMHANDLE) {
+    unsafe { (lib().fn_FPDFDOC_ExitFormFillEnvironment)(form) }
+}
+
+/// Type of an interactive form field
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfiumFormFieldType {
+    /// Single or multi-line text field
+    TextField,
+    /// Check box
+    CheckBox,
+    /// Radio button, part of a group
+    RadioButton,
+    /// Drop-down list, optionally editable
+    ComboBox,
+    /// Scrollable list
+    ListBox,
+    /// Push button
+    PushButton,
+    /// Digital signature field
+    Signature,
+}
+
+impl PdfiumFormFieldType {
+    fn from_raw(value: i32) -> Option<Self> {
+        match value {
+            FPDF_FORMFIELD_TEXTFIELD => Some(Self::TextField),
+            FPDF_FORMFIELD_CHECKBOX => Some(Self::CheckBox),
+            FPDF_FORMFIELD_RADIOBUTTON => Some(Self::RadioButton),
+            FPDF_FORMFIELD_COMBOBOX => Some(Self::ComboBox),
+            FPDF_FORMFIELD_LISTBOX => Some(Self::ListBox),
+            FPDF_FORMFIELD_PUSHBUTTON => Some(Self::PushButton),
+            FPDF_FORMFIELD_SIGNATURE => Some(Self::Signature),
+            _ => None,
+        }
+    }
+}
+
+/// Option of a combo box or list box form field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfiumFormOption {
+    /// Display label of the option
+    pub label: String,
+    /// Whether the option is currently selected
+    pub selected: bool,
+}
+
+/// Descriptor of an interactive form field widget on a page
+#[derive(Debug, Clone)]
+pub struct PdfiumFormField {
+    /// Fully qualified name of the field
+    pub name: String,
+    /// Type of the field
+    pub field_type: PdfiumFormFieldType,
+    /// Current value of the field, if any
+    pub value: Option<String>,
+    /// The widget annotation backing the field
+    pub annotation: PdfiumAnnotation,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_form_fields() {
+        let document = PdfiumDocument::new_from_path("resources/form.pdf", None).unwrap();
+        let form = PdfiumForm::new(&document).unwrap();
+        let page = document.page(0).unwrap();
+        let fields = form.fields(&page);
+
+        use PdfiumFormFieldType::*;
+        let names_and_types: Vec<_> = fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.field_type))
+            .collect();
+        assert_eq!(
+            names_and_types,
+            vec![
+                ("name", TextField),
+                ("subscribe", CheckBox),
+                ("color", RadioButton),
+                ("color", RadioButton),
+                ("country", ComboBox),
+                ("fruits", ListBox),
+                ("submit", PushButton),
+                ("signature", Signature),
+            ]
+        );
+
+        assert_eq!(fields[0].value.as_deref(), Some("Alice"));
+        assert_eq!(fields[4].value.as_deref(), Some("Germany"));
+        assert_eq!(fields[7].value, None);
+        assert_eq!(
+            fields[4].annotation.subtype(),
+            pdfium_constants::FPDF_ANNOT_WIDGET
+        );
+    }
+
+    #[test]
+    fn test_form_set_checked() {
+        let document = PdfiumDocument::new_from_path("resources/form.pdf", None).unwrap();
+        let form = PdfiumForm::new(&document).unwrap();
+        let page = document.page(0).unwrap();
+        let fields = form.fields(&page);
+
+        let subscribe = &fields[1].annotation;
+        assert!(!form.is_checked(subscribe));
+        form.set_checked(&page, subscribe, true).unwrap();
+        assert!(form.is_checked(subscribe));
+
+        // Radio group: checking one button unchecks its sibling
+        let (red, blue) = (&fields[2].annotation, &fields[3].annotation);
+        form.set_checked(&page, red, true).unwrap();
+        assert!(form.is_checked(red));
+        form.set_checked(&page, blue, true).unwrap();
+        assert!(form.is_checked(blue));
+        assert!(!form.is_checked(red));
+
+        // Text fields cannot be checked
+        assert!(form
+            .set_checked(&page, &fields[0].annotation, true)
+            .is_err());
+
+        let bytes = document.save_to_bytes(None).unwrap();
+        drop(fields);
+        drop(page);
+        drop(form);
+
+        let document = PdfiumDocument::new_from_reader(std::io::Cursor::new(bytes), None).unwrap();
+        let form = PdfiumForm::new(&document).unwrap();
+        let page = document.page(0).unwrap();
+        let fields = form.fields(&page);
+        assert!(form.is_checked(&fields[1].annotation));
+        assert!(!form.is_checked(&fields[2].annotation));
+        assert!(form.is_checked(&fields[3].annotation));
+    }
+
+    #[test]
+    fn test_form_options() {
+        let document = PdfiumDocument::new_from_path("resources/form.pdf", None).unwrap();
+        let form = PdfiumForm::new(&document).unwrap();
+        let page = document.page(0).unwrap();
+        let fields = form.fields(&page);
+
+        let country = &fields[4].annotation;
+        let option = |label: &str, selected| PdfiumFormOption {
+            label: label.into(),
+            selected,
+        };
+        assert_eq!(
+            form.options(country),
+            vec![
+                option("Netherlands", false),
+                option("Germany", true),
+                option("Belgium", false),
+            ]
+        );
+
+        form.set_option_selected(&page, country, 0, true).unwrap();
+        assert!(lib().FPDFAnnot_IsOptionSelected(&form, country, 0).is_ok());
+        assert!(lib().FPDFAnnot_IsOptionSelected(&form, country, 1).is_err());
+        assert!(form.set_option_selected(&page, country, 0, false).is_err());
+
+        let fruits = &fields[5].annotation;
+        form.set_option_selected(&page, fruits, 1, true).unwrap();
+        let selected: Vec<_> = form
+            .options(fruits)
+            .into_iter()
+            .filter(|option| option.selected)
+            .map(|option| option.label)
+            .collect();
+        assert!(selected.contains(&"Banana".to_string()));
+
+        assert!(form.options(&fields[0].annotation).is_empty());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_form_to_json() {
+        let document = PdfiumDocument::new_from_path("resources/form.pdf", None).unwrap();
+        let form = PdfiumForm::new(&document).unwrap();
+        assert_eq!(
+            form.to_json(&document).unwrap(),
+            concat!(
+                r#"{"name":"Alice","subscribe":false,"color":null,"#,
+                r#""country":["Germany"],"fruits":["Apple","Cherry"]}"#,
+            )
+        );
+
+        let page = document.page(0).unwrap();
+        let fields = form.fields(&page);
+        form.set_text(&page, &fields[0].annotation, "Bob \"B\"")
+            .unwrap();
+        form.set_checked(&page, &fields[1].annotation, true)
+            .unwrap();
+        form.set_checked(&page, &fields[3].annotation, true)
+            .unwrap();
+        form.set_option_selected(&page, &fields[4].annotation, 2, true)
+            .unwrap();
+        assert_eq!(
+            form.to_json(&document).unwrap(),
+            concat!(
+                r#"{"name":"Bob \"B\"","subscribe":true,"color":"Blue","#,
+                r#""country":["Belgium"],"fruits":["Apple","Cherry"]}"#,
+            )
+        );
+    }
+}
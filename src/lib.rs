@@ -29,6 +29,7 @@ mod bookmark;
 mod c_api;
 mod clip_path;
 mod color;
+mod deadline;
 mod destination;
 mod document;
 mod error;
@@ -36,25 +37,36 @@ mod font;
 mod form;
 mod glyph_path;
 mod javascript_action;
+#[cfg(feature = "json")]
+mod json;
 mod link;
 mod matrix;
 mod page;
 mod path_segment;
+mod point;
+pub mod prelude;
+mod quad;
 mod rect;
 mod signature;
 mod struct_element;
 mod struct_element_attr;
 mod struct_element_attr_value;
 mod struct_tree;
+#[cfg(test)]
+mod test_util;
 mod xobject;
 
 pub use action::PdfiumAction;
+pub use annotation::PdfiumAnnotAction;
 pub use annotation::PdfiumAnnotation;
+pub use annotation::PdfiumAnnotationRef;
 pub use attachment::PdfiumAttachment;
 pub use availability::PdfiumAvailability;
 pub use bitmap::PdfiumBitmap;
 pub use bitmap::PdfiumBitmapFormat;
+pub use bitmap::PdfiumBorrowedBitmap;
 pub use bookmark::PdfiumBookmark;
+pub use bookmark::PdfiumBookmarks;
 pub use c_api::guard::lib;
 pub use c_api::guard::set_library_location;
 pub use c_api::guard::set_use_skia;
@@ -64,33 +76,53 @@ pub use c_api::pdfium_constants;
 pub use c_api::pdfium_types;
 pub use clip_path::PdfiumClipPath;
 pub use color::PdfiumColor;
+pub use deadline::PdfiumDeadline;
 pub use destination::PdfiumDestination;
+pub use document::diff::PdfiumPageTextDiff;
+pub use document::metadata::PdfiumDocumentMetadata;
 pub use document::reader::PdfiumReader;
+pub use document::stats::PdfiumContentStats;
+pub use document::validation::PdfiumValidationReport;
 pub use document::PdfiumDocument;
 pub use error::PdfiumError;
+pub use error::PdfiumLibraryError;
 pub use error::PdfiumResult;
 pub use font::PdfiumFont;
-pub use form::PdfiumForm;
+pub use form::{PdfiumForm, PdfiumFormField, PdfiumFormFieldType, PdfiumFormOption};
 pub use glyph_path::PdfiumGlyphPath;
 pub use javascript_action::PdfiumJavascriptAction;
 pub use link::PdfiumLink;
 pub use matrix::PdfiumMatrix;
+pub use matrix::PdfiumTransform;
 pub use page::boundaries::PdfiumPageBoundaries;
+pub use page::link::PdfiumClickable;
+pub use page::link::PdfiumLinkTarget;
 pub use page::link::PdfiumPageLink;
 pub use page::object::mark::PdfiumPageObjectMark;
+pub use page::object::shading::PdfiumShadingInfo;
 pub use page::object::PdfiumPageObject;
 pub use page::range::PdfiumPageRange;
 pub use page::render::PdfiumRenderConfig;
 pub use page::render::PdfiumRenderFlags;
+pub use page::render::PdfiumResolvedRender;
+pub use page::selection::PdfiumPageSelection;
 pub use page::text::search::PdfiumSearch;
 pub use page::text::search::PdfiumSearchFlags;
+pub use page::text::search::PdfiumSearchHit;
+pub use page::text::search::PdfiumSearchIterator;
+pub use page::text::search::PdfiumSearchResult;
+pub use page::text::table::PdfiumTable;
 pub use page::text::PdfiumTextPage;
 pub use page::PdfiumPage;
+pub use page::PdfiumPageRotation;
+pub use page::PdfiumResizeMode;
 pub use path_segment::PdfiumPathSegment;
+pub use point::PdfiumPoint;
+pub use quad::PdfiumQuad;
 pub use rect::PdfiumRect;
 pub use signature::PdfiumSignature;
 pub use struct_element::PdfiumStructElement;
 pub use struct_element_attr::PdfiumStructElementAttr;
-pub use struct_element_attr_value::PdfiumStructElementAttrValue;
+pub use struct_element_attr_value::{PdfiumStructAttrValue, PdfiumStructElementAttrValue};
 pub use struct_tree::PdfiumStructTree;
 pub use xobject::PdfiumXObject;
@@ -94,6 +94,36 @@ impl PdfiumMatrix {
         }
     }
 
+    /// Decomposes this [`PdfiumMatrix`] into human readable [`PdfiumTransform`] components.
+    ///
+    /// The matrix is decomposed as a scale (with shear along the x-axis), followed by a
+    /// rotation and a translation. A flipped matrix (negative determinant) results in a
+    /// negative `scale_y`. A degenerate matrix that collapses everything onto a line or a
+    /// point results in a zero scale for the collapsed direction(s).
+    pub fn decompose(&self) -> PdfiumTransform {
+        let (a, b, c, d) = (self.a, self.b, self.c, self.d);
+        let scale_x = a.hypot(b);
+        let (scale_y, rotation_radians, shear) = if scale_x != 0.0 {
+            let determinant = a * d - b * c;
+            (
+                determinant / scale_x,
+                b.atan2(a),
+                (a * c + b * d) / (scale_x * scale_x),
+            )
+        } else {
+            // Everything is collapsed onto the y-axis, take the rotation from there
+            (c.hypot(d), (-c).atan2(d), 0.0)
+        };
+        PdfiumTransform {
+            scale_x,
+            scale_y,
+            rotation_radians,
+            shear,
+            translate_x: self.e,
+            translate_y: self.f,
+        }
+    }
+
     /// Creates a new [`PdfiumMatrix`] object with matrix values `a` and `d` set to `1.0`
     /// and all other values set to `0.0`.
     pub const fn identity() -> Self {
@@ -106,6 +136,89 @@ impl PdfiumMatrix {
             f: 0.0,
         }
     }
+
+    /// Returns the [`PdfiumMatrix`] that applies this matrix first and `other` second.
+    ///
+    /// For an object inside a form object, `object.then(&form)` maps the object space onto the
+    /// space containing the form object.
+    pub fn then(&self, other: &PdfiumMatrix) -> PdfiumMatrix {
+        PdfiumMatrix {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+            e: self.e * other.a + self.f * other.c + other.e,
+            f: self.e * other.b + self.f * other.d + other.f,
+        }
+    }
+
+    /// Applies this [`PdfiumMatrix`] to the point (`x`, `y`).
+    pub fn transform_point(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+
+    /// Returns the matrix values of this [`PdfiumMatrix`] in `[a, b, c, d, e, f]` order.
+    pub const fn as_array(&self) -> [f32; 6] {
+        [self.a, self.b, self.c, self.d, self.e, self.f]
+    }
+}
+
+impl Default for PdfiumMatrix {
+    /// Returns the [`PdfiumMatrix::identity`] matrix.
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl From<[f32; 6]> for PdfiumMatrix {
+    /// Creates a [`PdfiumMatrix`] from matrix values in `[a, b, c, d, e, f]` order.
+    fn from([a, b, c, d, e, f]: [f32; 6]) -> Self {
+        Self::new(a, b, c, d, e, f)
+    }
+}
+
+impl From<(f32, f32, f32, f32, f32, f32)> for PdfiumMatrix {
+    /// Creates a [`PdfiumMatrix`] from matrix values in `(a, b, c, d, e, f)` order.
+    fn from((a, b, c, d, e, f): (f32, f32, f32, f32, f32, f32)) -> Self {
+        Self::new(a, b, c, d, e, f)
+    }
+}
+
+/// Affine transformation decomposed into scale, shear, rotation and translation
+///
+/// See [`PdfiumMatrix::decompose`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfiumTransform {
+    /// Horizontal scale factor
+    pub scale_x: f32,
+    /// Vertical scale factor, negative if the transformation flips
+    pub scale_y: f32,
+    /// Counter-clockwise rotation in radians
+    pub rotation_radians: f32,
+    /// Shear factor along the x-axis
+    pub shear: f32,
+    /// Horizontal translation
+    pub translate_x: f32,
+    /// Vertical translation
+    pub translate_y: f32,
+}
+
+impl From<&PdfiumTransform> for PdfiumMatrix {
+    fn from(t: &PdfiumTransform) -> Self {
+        let (sin, cos) = t.rotation_radians.sin_cos();
+        let shear_x = t.shear * t.scale_x;
+        Self::new(
+            t.scale_x * cos,
+            t.scale_x * sin,
+            shear_x * cos - t.scale_y * sin,
+            shear_x * sin + t.scale_y * cos,
+            t.translate_x,
+            t.translate_y,
+        )
+    }
 }
 
 impl From<&PdfiumMatrix> for FS_MATRIX {
@@ -127,3 +240,111 @@ impl From<FS_MATRIX> for PdfiumMatrix {
         Self::new(matrix.a, matrix.b, matrix.c, matrix.d, matrix.e, matrix.f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_6;
+
+    use super::*;
+
+    const EPSILON: f32 = 1e-5;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < EPSILON,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn test_decompose() {
+        // Scale (2, 3), rotate 30 degrees, translate (10, 20)
+        let (sin, cos) = FRAC_PI_6.sin_cos();
+        let matrix = PdfiumMatrix::new(2.0 * cos, 2.0 * sin, -3.0 * sin, 3.0 * cos, 10.0, 20.0);
+        let t = matrix.decompose();
+        assert_close(t.scale_x, 2.0);
+        assert_close(t.scale_y, 3.0);
+        assert_close(t.rotation_radians, FRAC_PI_6);
+        assert_close(t.shear, 0.0);
+        assert_close(t.translate_x, 10.0);
+        assert_close(t.translate_y, 20.0);
+    }
+
+    #[test]
+    fn test_decompose_flip_and_shear() {
+        // Vertical flip
+        let t = PdfiumMatrix::new(1.0, 0.0, 0.0, -1.0, 0.0, 0.0).decompose();
+        assert_close(t.scale_x, 1.0);
+        assert_close(t.scale_y, -1.0);
+        assert_close(t.rotation_radians, 0.0);
+
+        // Shear survives a round trip
+        let matrix = PdfiumMatrix::new(1.5, 0.5, 0.8, -2.0, 3.0, 4.0);
+        let FS_MATRIX { a, b, c, d, e, f } = (&PdfiumMatrix::from(&matrix.decompose())).into();
+        for (actual, expected) in [a, b, c, d, e, f]
+            .into_iter()
+            .zip([1.5, 0.5, 0.8, -2.0, 3.0, 4.0])
+        {
+            assert_close(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_decompose_degenerate() {
+        let t = PdfiumMatrix::zero().decompose();
+        assert_eq!((t.scale_x, t.scale_y, t.shear), (0.0, 0.0, 0.0));
+
+        // Collapsed onto the y-axis, rotated 90 degrees
+        let t = PdfiumMatrix::new(0.0, 0.0, -2.0, 0.0, 0.0, 0.0).decompose();
+        assert_close(t.scale_x, 0.0);
+        assert_close(t.scale_y, 2.0);
+        assert_close(t.rotation_radians, std::f32::consts::FRAC_PI_2);
+    }
+
+    #[test]
+    fn test_then() {
+        let scale = PdfiumMatrix::new_scale(2.0);
+        let pan = PdfiumMatrix::new_scale_pan(1.0, 10.0, 20.0);
+        assert_eq!(scale.then(&pan).transform_point(1.0, 1.0), (12.0, 22.0));
+        assert_eq!(pan.then(&scale).transform_point(1.0, 1.0), (22.0, 42.0));
+        assert_eq!(
+            scale.then(&PdfiumMatrix::identity()).as_array(),
+            scale.as_array()
+        );
+    }
+
+    #[test]
+    fn test_default_is_identity() {
+        assert_eq!(
+            PdfiumMatrix::default().as_array(),
+            PdfiumMatrix::identity().as_array()
+        );
+        assert_eq!(
+            PdfiumMatrix::default().as_array(),
+            [1.0, 0.0, 0.0, 1.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_array_and_tuple_conversions() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let matrix = PdfiumMatrix::from(values);
+        assert_eq!(matrix.as_array(), values);
+        let tuple = PdfiumMatrix::from((1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+        assert_eq!(tuple.as_array(), values);
+
+        // Same order as the FS_MATRIX passed to PDFium by e.g. `set_matrix`
+        let fs_matrix = FS_MATRIX::from(&matrix);
+        assert_eq!(
+            [
+                fs_matrix.a,
+                fs_matrix.b,
+                fs_matrix.c,
+                fs_matrix.d,
+                fs_matrix.e,
+                fs_matrix.f
+            ],
+            values
+        );
+    }
+}
@@ -17,7 +17,7 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::{error::PdfiumResult, lib, page::PdfiumPage, PdfiumRect};
+use crate::{error::PdfiumResult, lib, page::PdfiumPage, pdfium_types::FS_RECTF, PdfiumRect};
 
 /// Rust interface to the boundary boxes of a page
 ///
@@ -95,6 +95,17 @@ impl<'a> PdfiumPageBoundaries<'a> {
         Ok(rect)
     }
 
+    /// Gets the bounding box of the page.
+    ///
+    /// This is the intersection between the MediaBox and the CropBox, i.e. the region of the
+    /// page that is actually visible.
+    #[inline]
+    pub fn bounding_box(&self) -> PdfiumResult<PdfiumRect> {
+        let mut rect = FS_RECTF::from(&PdfiumRect::zero());
+        lib().FPDF_GetPageBoundingBox(self.page, &mut rect)?;
+        Ok(rect.into())
+    }
+
     /// Gets the "CropBox" entry from the page dictionary.
     ///
     /// The CropBox defines the visible region of default user space. When the page is displayed
@@ -172,6 +183,35 @@ impl<'a> PdfiumPageBoundaries<'a> {
             .or_else(|_| self.trim())
             .or_else(|_| self.media())
     }
+
+    /// Sets the "ArtBox" entry in the page dictionary to `rect`, see [`PdfiumPageBoundaries::art`].
+    pub fn set_art(&self, rect: PdfiumRect) {
+        lib().FPDFPage_SetArtBox(self.page, rect.left, rect.bottom, rect.right, rect.top);
+    }
+
+    /// Sets the "BleedBox" entry in the page dictionary to `rect`, see
+    /// [`PdfiumPageBoundaries::bleed`].
+    pub fn set_bleed(&self, rect: PdfiumRect) {
+        lib().FPDFPage_SetBleedBox(self.page, rect.left, rect.bottom, rect.right, rect.top);
+    }
+
+    /// Sets the "CropBox" entry in the page dictionary to `rect`, see
+    /// [`PdfiumPageBoundaries::crop`].
+    pub fn set_crop(&self, rect: PdfiumRect) {
+        lib().FPDFPage_SetCropBox(self.page, rect.left, rect.bottom, rect.right, rect.top);
+    }
+
+    /// Sets the "MediaBox" entry in the page dictionary to `rect`, see
+    /// [`PdfiumPageBoundaries::media`].
+    pub fn set_media(&self, rect: PdfiumRect) {
+        lib().FPDFPage_SetMediaBox(self.page, rect.left, rect.bottom, rect.right, rect.top);
+    }
+
+    /// Sets the "TrimBox" entry in the page dictionary to `rect`, see
+    /// [`PdfiumPageBoundaries::trim`].
+    pub fn set_trim(&self, rect: PdfiumRect) {
+        lib().FPDFPage_SetTrimBox(self.page, rect.left, rect.bottom, rect.right, rect.top);
+    }
 }
 
 #[cfg(test)]
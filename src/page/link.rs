@@ -17,12 +17,144 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::ptr::null_mut;
+
 use crate::{
+    c_api::utf16::utf16_to_string,
     error::{PdfiumError, PdfiumResult},
     lib,
-    pdfium_types::{Handle, PageLinkHandle, FPDF_PAGELINK},
+    pdfium_constants::{PDFACTION_GOTO, PDFACTION_URI},
+    pdfium_types::{Handle, PageLinkHandle, FPDF_LINK, FPDF_PAGELINK, FS_RECTF},
+    PdfiumDocument, PdfiumLink, PdfiumPage, PdfiumRect,
 };
 
+/// Target of a [`PdfiumClickable`] region
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfiumLinkTarget {
+    /// External URL
+    Url(String),
+    /// Zero-based index of a page in the same document
+    Page(i32),
+}
+
+/// Clickable region of a page, see [`PdfiumPage::all_clickable_regions`]
+#[derive(Debug, Clone)]
+pub struct PdfiumClickable {
+    /// Clickable area in page coordinates
+    pub rect: PdfiumRect,
+    /// Where the region links to
+    pub target: PdfiumLinkTarget,
+}
+
+impl PdfiumPage {
+    /// Returns all clickable regions of this [`PdfiumPage`], for building a clickable overlay.
+    ///
+    /// This combines the link annotations of the page with the URLs that PDFium detects in
+    /// the page text (web links). Link annotations come first, in page order. Web links are
+    /// only added where they do not overlap a link annotation, as those already make the text
+    /// clickable. A web link spanning multiple lines results in a region per line. Link
+    /// annotations with other targets than a URL or a page in this document (e.g. launch
+    /// actions) are skipped.
+    pub fn all_clickable_regions(&self) -> PdfiumResult<Vec<PdfiumClickable>> {
+        let document = self.owner.clone().ok_or(PdfiumError::InvokationFailed)?;
+        let mut regions = Vec::new();
+
+        let mut start_pos = 0;
+        let mut raw: FPDF_LINK = null_mut();
+        while lib()
+            .FPDFLink_Enumerate(self.into(), &mut start_pos, &mut raw)
+            .is_ok()
+        {
+            let link = PdfiumLink::new_from_handle(raw)?;
+            let mut rect = FS_RECTF::from(&PdfiumRect::zero());
+            lib().FPDFLink_GetAnnotRect(&link, &mut rect)?;
+            if let Some(target) = link_target(&document, &link) {
+                regions.push(PdfiumClickable {
+                    rect: rect.into(),
+                    target,
+                });
+            }
+        }
+        let annotation_count = regions.len();
+
+        let text = self.text()?;
+        let web_links = lib().FPDFLink_LoadWebLinks(&text)?;
+        for index in 0..lib().FPDFLink_CountWebLinks(&web_links) {
+            let Some(url) = web_link_url(&web_links, index) else {
+                continue;
+            };
+            for rect_index in 0..lib().FPDFLink_CountRects(&web_links, index) {
+                let (mut left, mut top, mut right, mut bottom) = (0.0, 0.0, 0.0, 0.0);
+                lib().FPDFLink_GetRect(
+                    &web_links,
+                    index,
+                    rect_index,
+                    &mut left,
+                    &mut top,
+                    &mut right,
+                    &mut bottom,
+                )?;
+                let rect = PdfiumRect::new(left as f32, top as f32, right as f32, bottom as f32);
+                if regions[..annotation_count]
+                    .iter()
+                    .any(|region| region.rect.intersects(&rect))
+                {
+                    continue;
+                }
+                regions.push(PdfiumClickable {
+                    rect,
+                    target: PdfiumLinkTarget::Url(url.clone()),
+                });
+            }
+        }
+        Ok(regions)
+    }
+}
+
+/// Returns the target of the link annotation `link`, from its destination or its action.
+fn link_target(document: &PdfiumDocument, link: &PdfiumLink) -> Option<PdfiumLinkTarget> {
+    let lib = lib();
+    let dest = match lib.FPDFLink_GetDest(document, link) {
+        Ok(dest) => dest,
+        Err(_) => {
+            let action = lib.FPDFLink_GetAction(link).ok()?;
+            match lib.FPDFAction_GetType(&action) as u32 {
+                PDFACTION_GOTO => lib.FPDFAction_GetDest(document, &action).ok()?,
+                PDFACTION_URI => {
+                    let len = lib.FPDFAction_GetURIPath(document, &action, None, 0);
+                    if len <= 1 {
+                        return None;
+                    }
+                    let mut buffer = vec![0u8; len as usize];
+                    lib.FPDFAction_GetURIPath(document, &action, Some(&mut buffer), len);
+                    buffer.pop(); // NUL terminator
+                    return Some(PdfiumLinkTarget::Url(
+                        String::from_utf8_lossy(&buffer).into_owned(),
+                    ));
+                }
+                _ => return None,
+            }
+        }
+    };
+    match lib.FPDFDest_GetDestPageIndex(document, &dest) {
+        index if index >= 0 => Some(PdfiumLinkTarget::Page(index)),
+        _ => None,
+    }
+}
+
+/// Returns the URL of the web link at `index`.
+fn web_link_url(web_links: &PdfiumPageLink, index: i32) -> Option<String> {
+    let lib = lib();
+    let mut empty = 0;
+    let len = lib.FPDFLink_GetURL(web_links, index, &mut empty, 0);
+    if len <= 1 {
+        return None;
+    }
+    let mut buffer = vec![0u16; len as usize];
+    let len = lib.FPDFLink_GetURL(web_links, index, &mut buffer[0], len);
+    Some(utf16_to_string(&buffer[..len.max(0) as usize]))
+}
+
 /// # Rust interface to FPDF_PAGELINK
 #[derive(Debug, Clone)]
 pub struct PdfiumPageLink {
@@ -50,3 +182,32 @@ impl From<&PdfiumPageLink> for FPDF_PAGELINK {
 fn close_page_link(page_link: FPDF_PAGELINK) {
     lib().FPDFLink_CloseWebLinks(page_link);
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_all_clickable_regions() {
+        let document = PdfiumDocument::new_from_path("resources/links.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let regions = page.all_clickable_regions().unwrap();
+        let targets: Vec<_> = regions.iter().map(|region| region.target.clone()).collect();
+        assert_eq!(
+            targets,
+            [
+                PdfiumLinkTarget::Url("https://dup.example.org".to_string()),
+                PdfiumLinkTarget::Page(1),
+                PdfiumLinkTarget::Url("https://example.com".to_string()),
+            ]
+        );
+
+        // The web link found in the text is located on its line
+        let web_link = &regions[2].rect;
+        assert!(web_link.bottom >= 290.0 && web_link.top <= 320.0);
+        assert!(web_link.left > 20.0);
+
+        let page = document.page(1).unwrap();
+        assert!(page.all_clickable_regions().unwrap().is_empty());
+    }
+}
@@ -22,15 +22,23 @@ pub mod link;
 pub mod object;
 pub mod pages;
 pub mod range;
+mod redact;
 pub mod render;
+pub mod selection;
 pub mod text;
 
+use std::{fmt::Write, ptr::null_mut};
+
 use crate::{
     error::{PdfiumError, PdfiumResult},
     lib,
-    page::{boundaries::PdfiumPageBoundaries, object::objects::PdfiumPageObjects},
-    pdfium_types::{Handle, PageHandle, FPDF_PAGE},
-    PdfiumDocument, PdfiumPageObject, PdfiumTextPage,
+    page::{
+        boundaries::PdfiumPageBoundaries,
+        object::{objects::PdfiumPageObjects, ObjectType},
+    },
+    pdfium_types::{Handle, PageHandle, FPDF_PAGE, FS_MATRIX},
+    PdfiumAnnotation, PdfiumColor, PdfiumDocument, PdfiumMatrix, PdfiumPageObject, PdfiumRect,
+    PdfiumRenderConfig, PdfiumTextPage,
 };
 
 /// # Rust interface to FPDF_PAGE
@@ -52,15 +60,84 @@ impl PdfiumPage {
         }
     }
 
+    pub(crate) fn new_from_page_handle(handle: PageHandle) -> Self {
+        Self {
+            handle,
+            owner: None,
+        }
+    }
+
     pub(crate) fn set_owner(&mut self, owner: PdfiumDocument) {
         self.owner = Some(owner);
     }
 
+    /// Records on the owning [`PdfiumDocument`] that the content stream of this page
+    /// needs regenerating before the document is saved.
+    pub(crate) fn mark_dirty(&self) {
+        if let Some(owner) = &self.owner {
+            owner.mark_page_dirty(&self.handle);
+        }
+    }
+
+    /// Returns `true` if this [`PdfiumPage`] has edits that have not yet been written to its
+    /// content stream.
+    ///
+    /// Always returns `false` when automatic content generation has been disabled with
+    /// [`PdfiumDocument::set_auto_generate_content`], as edits are then no longer tracked.
+    pub fn is_dirty(&self) -> bool {
+        self.owner
+            .as_ref()
+            .is_some_and(|owner| owner.is_page_dirty(&self.handle))
+    }
+
+    /// Generates the content stream of this [`PdfiumPage`] from its page objects.
+    ///
+    /// Edits made to page objects only live in memory until the content is generated.
+    /// Normally there is no need to call this, as [`PdfiumDocument`] regenerates the
+    /// content of all edited pages before saving. Call it directly when automatic content
+    /// generation has been disabled with [`PdfiumDocument::set_auto_generate_content`].
+    pub fn generate_content(&self) -> PdfiumResult<()> {
+        lib().FPDFPage_GenerateContent(self)?;
+        if let Some(owner) = &self.owner {
+            owner.clear_page_dirty(&self.handle);
+        }
+        Ok(())
+    }
+
     /// Rust interface to the boundary boxes of a page
     pub fn boundaries(&self) -> PdfiumPageBoundaries<'_> {
         PdfiumPageBoundaries::new(self)
     }
 
+    /// Returns the width of this [`PdfiumPage`] in points.
+    ///
+    /// This is the width as displayed, so the height of the page box for pages rotated by
+    /// 90 or 270 degrees.
+    pub fn width(&self) -> f32 {
+        lib().FPDF_GetPageWidthF(self)
+    }
+
+    /// Returns the height of this [`PdfiumPage`] in points.
+    ///
+    /// This is the height as displayed, so the width of the page box for pages rotated by
+    /// 90 or 270 degrees.
+    pub fn height(&self) -> f32 {
+        lib().FPDF_GetPageHeightF(self)
+    }
+
+    /// Returns the rotation of this [`PdfiumPage`] when displayed.
+    pub fn rotation(&self) -> PdfiumPageRotation {
+        lib().FPDFPage_GetRotation(self).into()
+    }
+
+    /// Sets the rotation of this [`PdfiumPage`] when displayed.
+    ///
+    /// Only the "Rotate" entry of the page dictionary changes, the content is untouched. See
+    /// [`PdfiumPage::bake_rotation`] to apply the rotation to the content instead.
+    pub fn set_rotation(&self, rotation: PdfiumPageRotation) {
+        lib().FPDFPage_SetRotation(self, rotation.into());
+    }
+
     /// Get number of page objects inside this [`PdfiumPage`].
     pub fn object_count(&self) -> i32 {
         lib().FPDFPage_CountObjects(self)
@@ -73,16 +150,367 @@ impl PdfiumPage {
         Ok(object)
     }
 
+    /// Returns the first [`PdfiumPageObject`] of this [`PdfiumPage`] with marked content ID
+    /// `mcid`, or `None` if there is no such object.
+    ///
+    /// In tagged PDFs, structure elements refer to page content through these IDs, see
+    /// [`PdfiumStructElement`](crate::PdfiumStructElement). Only top-level objects are
+    /// searched, not the contents of form objects.
+    pub fn object_with_mcid(&self, mcid: i32) -> Option<PdfiumPageObject> {
+        self.objects()
+            .filter_map(Result::ok)
+            .find(|object| object.get_marked_content_id() == mcid)
+    }
+
+    /// Returns the topmost [`PdfiumPageObject`] of this [`PdfiumPage`] whose bounds contain the
+    /// page-space point (`x`, `y`), or `None` if there is no such object.
+    ///
+    /// Objects are tested in reverse drawing order, so an object drawn on top of another wins.
+    /// Hit testing uses the bounding box of each object (see [`PdfiumPageObject::bounds`]),
+    /// so a point near a diagonal line or inside an unfilled path also counts as a hit. Only
+    /// top-level objects are tested, not the contents of form objects.
+    pub fn object_at(&self, x: f32, y: f32) -> Option<PdfiumPageObject> {
+        (0..self.object_count())
+            .rev()
+            .filter_map(|index| self.object(index).ok())
+            .find(|object| object.bounds().is_ok_and(|bounds| bounds.contains(x, y)))
+    }
+
+    /// Inserts `object` into this [`PdfiumPage`] at position `index`.
+    ///
+    /// Page objects are drawn in order, so an object at a lower index is drawn first and
+    /// ends up behind objects with a higher index. Inserting at index 0 therefore places
+    /// the object behind all existing content (e.g. a watermark underneath the text),
+    /// while inserting at [`PdfiumPage::object_count`] appends it and draws it on top.
+    ///
+    /// `object` must have been newly created (e.g. by `FPDFPageObj_CreateNewRect`).
+    /// Ownership of the object is transferred to the page.
+    ///
+    /// Returns an error if `index` is negative or greater than the object count.
+    pub fn insert_object_at(&self, object: PdfiumPageObject, index: i32) -> PdfiumResult<()> {
+        if index < 0 || index > self.object_count() {
+            return Err(PdfiumError::InvokationFailed);
+        }
+        lib()
+            .FPDFPage_InsertObjectAtIndex(self, &object, index as usize)
+            .inspect(|_| self.mark_dirty())
+    }
+
+    /// Applies `matrix` to every top-level object of this [`PdfiumPage`] and regenerates
+    /// the page content.
+    ///
+    /// This can be used to flip, skew or translate everything on the page at once. Objects
+    /// nested inside form objects are not transformed individually, they inherit the
+    /// transform applied to their parent form object.
+    pub fn transform_content(&self, matrix: &PdfiumMatrix) -> PdfiumResult<()> {
+        let matrix = FS_MATRIX::from(matrix);
+        for index in 0..self.object_count() {
+            self.object(index)?.transform_f(&matrix)?;
+        }
+        self.generate_content()
+    }
+
+    /// Resizes this [`PdfiumPage`] to `width` x `height` points and scales its content to fit.
+    ///
+    /// The visible area of the page (see [`PdfiumPageBoundaries::default`]) is mapped onto the
+    /// new size according to `mode`, and the MediaBox and CropBox are replaced by
+    /// `[0 0 width height]`. Annotations are transformed along with the content. Sizes are in
+    /// unrotated page space, other boundary boxes are left unchanged.
+    pub fn resize_to(&self, width: f32, height: f32, mode: PdfiumResizeMode) -> PdfiumResult<()> {
+        if !(width > 0.0 && height > 0.0 && width.is_finite() && height.is_finite()) {
+            return Err(PdfiumError::InvalidConfiguration(
+                "Page size must be positive".to_string(),
+            ));
+        }
+        let source = self.boundaries().default()?;
+        if source.width() <= 0.0 || source.height() <= 0.0 {
+            return Err(PdfiumError::PageError);
+        }
+        let (scale_x, scale_y) = (width / source.width(), height / source.height());
+        let (scale_x, scale_y) = match mode {
+            PdfiumResizeMode::Stretch => (scale_x, scale_y),
+            PdfiumResizeMode::FitKeepAspect => {
+                let scale = scale_x.min(scale_y);
+                (scale, scale)
+            }
+        };
+        // Center the scaled content, leaving equal margins on both sides
+        let pan_x = (width - source.width() * scale_x) / 2.0 - source.left * scale_x;
+        let pan_y = (height - source.height() * scale_y) / 2.0 - source.bottom * scale_y;
+        let matrix = PdfiumMatrix::new(scale_x, 0.0, 0.0, scale_y, pan_x, pan_y);
+
+        self.transform_content(&matrix)?;
+        lib().FPDFPage_TransformAnnots(
+            self,
+            scale_x as f64,
+            0.0,
+            0.0,
+            scale_y as f64,
+            pan_x as f64,
+            pan_y as f64,
+        );
+        lib().FPDFPage_SetMediaBox(self, 0.0, 0.0, width, height);
+        lib().FPDFPage_SetCropBox(self, 0.0, 0.0, width, height);
+        Ok(())
+    }
+
+    /// Bakes the /Rotate attribute of this [`PdfiumPage`] into its content.
+    ///
+    /// The content, the annotations and the boundary boxes are rotated so that the page looks
+    /// the same as before, after which the rotation is reset to 0. This helps tools that ignore
+    /// the /Rotate attribute. Pages that are not rotated are left untouched.
+    pub fn bake_rotation(&self) -> PdfiumResult<()> {
+        let lib = lib();
+        let rotation = lib.FPDFPage_GetRotation(self).rem_euclid(4);
+        if rotation == 0 {
+            return Ok(());
+        }
+        let bounds = self.boundaries().default()?;
+        let (l, b, r, t) = (bounds.left, bounds.bottom, bounds.right, bounds.top);
+        // Rotate clockwise, moving the visible area to the origin
+        let matrix = match rotation {
+            1 => PdfiumMatrix::new(0.0, -1.0, 1.0, 0.0, -b, r),
+            2 => PdfiumMatrix::new(-1.0, 0.0, 0.0, -1.0, r, t),
+            _ => PdfiumMatrix::new(0.0, 1.0, -1.0, 0.0, t, -l),
+        };
+        let transform = |rect: PdfiumRect| {
+            let (x1, y1) = matrix.transform_point(rect.left, rect.bottom);
+            let (x2, y2) = matrix.transform_point(rect.right, rect.top);
+            (x1.min(x2), y1.min(y2), x1.max(x2), y1.max(y2))
+        };
+        let boundaries = self.boundaries();
+        let boxes = (
+            boundaries.media().map(transform),
+            boundaries.crop().map(transform),
+            boundaries.trim().map(transform),
+            boundaries.bleed().map(transform),
+            boundaries.art().map(transform),
+        );
+
+        self.transform_content(&matrix)?;
+        let [a, b, c, d, e, f] = matrix.as_array().map(|v| v as f64);
+        lib.FPDFPage_TransformAnnots(self, a, b, c, d, e, f);
+        if let Ok((l, b, r, t)) = boxes.0 {
+            lib.FPDFPage_SetMediaBox(self, l, b, r, t);
+        }
+        if let Ok((l, b, r, t)) = boxes.1 {
+            lib.FPDFPage_SetCropBox(self, l, b, r, t);
+        }
+        if let Ok((l, b, r, t)) = boxes.2 {
+            lib.FPDFPage_SetTrimBox(self, l, b, r, t);
+        }
+        if let Ok((l, b, r, t)) = boxes.3 {
+            lib.FPDFPage_SetBleedBox(self, l, b, r, t);
+        }
+        if let Ok((l, b, r, t)) = boxes.4 {
+            lib.FPDFPage_SetArtBox(self, l, b, r, t);
+        }
+        lib.FPDFPage_SetRotation(self, 0);
+        Ok(())
+    }
+
+    /// Flattens this [`PdfiumPage`] into a single image rendered at `dpi`.
+    ///
+    /// The page is rendered on a white background, all its objects are removed, and the
+    /// rendering is inserted as one image object covering the visible area of the page (see
+    /// [`PdfiumPageBoundaries::default`]). The page then looks the same in every viewer, but
+    /// its text can no longer be selected, searched or extracted, and vector content loses
+    /// its resolution independence. Annotations are not part of the page content and are
+    /// left untouched.
+    ///
+    /// A rotated page is first baked (see [`PdfiumPage::bake_rotation`]), so the resulting
+    /// page has no /Rotate attribute and the image is stored upright. The original objects
+    /// are destroyed, so handles to them obtained earlier must not be used afterwards.
+    pub fn rasterize(&self, dpi: f32) -> PdfiumResult<()> {
+        if dpi <= 0.0 || !dpi.is_finite() {
+            return Err(PdfiumError::InvalidConfiguration(
+                "DPI must be positive".to_string(),
+            ));
+        }
+        let document = self.owner.clone().ok_or(PdfiumError::InvokationFailed)?;
+        self.bake_rotation()?;
+        let bounds = self.boundaries().default()?;
+        let scale = dpi / 72.0;
+        let width = ((bounds.width() * scale).round() as i32).max(1);
+        let height = ((bounds.height() * scale).round() as i32).max(1);
+        let config = PdfiumRenderConfig::new()
+            .with_size(width, height)
+            .with_scale(scale)
+            .with_background(PdfiumColor::WHITE);
+        let bitmap = self.render(&config)?;
+
+        let image = lib().FPDFPageObj_NewImageObj(&document)?;
+        lib().FPDFImageObj_SetBitmap(null_mut(), 0, (&image).into(), (&bitmap).into())?;
+        image.set_matrix(&FS_MATRIX {
+            a: bounds.width(),
+            b: 0.0,
+            c: 0.0,
+            d: bounds.height(),
+            e: bounds.left,
+            f: bounds.bottom,
+        })?;
+
+        for index in (0..self.object_count()).rev() {
+            let object = self.object(index)?;
+            lib().FPDFPage_RemoveObject(self, &object)?;
+            lib().FPDFPageObj_Destroy((&object).into());
+        }
+        self.insert_object_at(image, 0)?;
+        self.generate_content()
+    }
+
     /// Return an [`Iterator`] for the ojects in this [`PdfiumPage`].
     pub fn objects(&self) -> PdfiumPageObjects<'_> {
         PdfiumPageObjects::new(self)
     }
 
+    /// Returns an [`Iterator`] over the objects in this [`PdfiumPage`] paired with their
+    /// bounds in page space, see [`PdfiumPageObject::bounds`].
+    ///
+    /// Convenient for building a spatial index of the page objects. Objects whose bounds
+    /// cannot be determined are skipped.
+    pub fn objects_with_bounds(&self) -> impl Iterator<Item = (PdfiumPageObject, PdfiumRect)> + '_ {
+        self.objects().filter_map(|object| {
+            let object = object.ok()?;
+            let bounds = object.bounds().ok()?;
+            Some((object, bounds))
+        })
+    }
+
+    /// Returns a textual dump of the top-level objects of this [`PdfiumPage`], for debugging.
+    ///
+    /// The public PDFium API does not expose the (decoded) content stream of a page, so the
+    /// raw content stream operators are not available. Instead, this lists one line per object
+    /// with its index, type, bounds (left, bottom, right, top) and transformation matrix
+    /// (a, b, c, d, e, f), e.g.
+    ///
+    /// ```text
+    /// 0: Path bounds=[10.00, 10.00, 30.00, 30.00] matrix=[1.00, 0.00, 0.00, 1.00, 0.00, 0.00]
+    /// ```
+    pub fn describe_objects(&self) -> PdfiumResult<String> {
+        let mut dump = String::new();
+        for (index, object) in self.objects().enumerate() {
+            let object = object?;
+            let (mut left, mut bottom, mut right, mut top) = (0.0, 0.0, 0.0, 0.0);
+            object.get_bounds(&mut left, &mut bottom, &mut right, &mut top)?;
+            let mut matrix: FS_MATRIX = (&PdfiumMatrix::identity()).into();
+            object.get_matrix(&mut matrix)?;
+            let _ = writeln!(
+                dump,
+                "{index}: {:?} bounds=[{left:.2}, {bottom:.2}, {right:.2}, {top:.2}] \
+                 matrix=[{:.2}, {:.2}, {:.2}, {:.2}, {:.2}, {:.2}]",
+                object.get_type(),
+                matrix.a,
+                matrix.b,
+                matrix.c,
+                matrix.d,
+                matrix.e,
+                matrix.f,
+            );
+        }
+        Ok(dump)
+    }
+
+    /// Get number of annotations inside this [`PdfiumPage`].
+    pub fn annotation_count(&self) -> i32 {
+        lib().FPDFPage_GetAnnotCount(self)
+    }
+
+    /// Returns the [`PdfiumAnnotation`] indicated by `index` from this [`PdfiumPage`].
+    pub fn annotation(&self, index: i32) -> PdfiumResult<PdfiumAnnotation> {
+        lib().FPDFPage_GetAnnot(self, index)
+    }
+
+    /// Returns the topmost [`PdfiumAnnotation`] whose rectangle contains the point
+    /// (`x`, `y`), given in page coordinates.
+    ///
+    /// Annotations are drawn in order, so the last matching annotation is the one on top.
+    /// Returns `None` if no annotation covers the point.
+    pub fn annotation_at(&self, x: f64, y: f64) -> Option<PdfiumAnnotation> {
+        (0..self.annotation_count()).rev().find_map(|index| {
+            let annotation = self.annotation(index).ok()?;
+            annotation
+                .rect()
+                .ok()?
+                .contains(x as f32, y as f32)
+                .then_some(annotation)
+        })
+    }
+
+    /// Returns `true` if this [`PdfiumPage`] contains no text, e.g. for a scanned page that
+    /// only holds an image.
+    ///
+    /// PDFium has no cheaper way to count characters than loading the text page (see
+    /// [`PdfiumPage::text`]), which extracts all characters of the page. This method avoids
+    /// that cost for pages without any text objects, and only loads the text page when the
+    /// page contains text (or form) objects that might produce characters.
+    pub fn is_empty_text(&self) -> PdfiumResult<bool> {
+        let may_contain_text = self.objects().any(|object| {
+            object.is_ok_and(|object| {
+                matches!(object.get_type(), ObjectType::Text | ObjectType::Form)
+            })
+        });
+        if !may_contain_text {
+            return Ok(true);
+        }
+        Ok(self.text()?.char_count()? == 0)
+    }
+
     /// Get text page information structure
     ///
     /// Contains information about all characters in a page.
     pub fn text(&self) -> PdfiumResult<PdfiumTextPage> {
-        lib().FPDFText_LoadPage(self)
+        let mut text = lib().FPDFText_LoadPage(self)?;
+        text.set_owner(self.clone());
+        Ok(text)
+    }
+}
+
+/// How [`PdfiumPage::resize_to`] fits the existing content into the new page size.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PdfiumResizeMode {
+    /// Scale horizontally and vertically to fill the new size, distorting the content if
+    /// the aspect ratio changes
+    Stretch,
+
+    /// Scale uniformly to fit within the new size and center the content, leaving margins
+    FitKeepAspect,
+}
+
+/// Clockwise rotation of a [`PdfiumPage`] when displayed, see [`PdfiumPage::rotation`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(i32)]
+pub enum PdfiumPageRotation {
+    /// Not rotated
+    None = 0,
+    /// Rotated 90 degrees clockwise
+    Clockwise90 = 1,
+    /// Rotated 180 degrees
+    Clockwise180 = 2,
+    /// Rotated 270 degrees clockwise (90 degrees counter-clockwise)
+    Clockwise270 = 3,
+}
+
+impl From<i32> for PdfiumPageRotation {
+    /// Converts the number of clockwise quarter turns as used by PDFium, modulo 4.
+    fn from(value: i32) -> Self {
+        match value.rem_euclid(4) {
+            1 => PdfiumPageRotation::Clockwise90,
+            2 => PdfiumPageRotation::Clockwise180,
+            3 => PdfiumPageRotation::Clockwise270,
+            _ => PdfiumPageRotation::None,
+        }
+    }
+}
+
+impl From<PdfiumPageRotation> for i32 {
+    fn from(value: PdfiumPageRotation) -> Self {
+        match value {
+            PdfiumPageRotation::None => 0,
+            PdfiumPageRotation::Clockwise90 => 1,
+            PdfiumPageRotation::Clockwise180 => 2,
+            PdfiumPageRotation::Clockwise270 => 3,
+        }
     }
 }
 
@@ -100,7 +528,193 @@ fn close_page(page: FPDF_PAGE) {
 
 #[cfg(test)]
 mod tests {
-    use crate::document::PdfiumDocument;
+    use std::{ffi::CString, io::Cursor};
+
+    use crate::{
+        document::PdfiumDocument,
+        lib,
+        page::object::ObjectType,
+        pdfium_constants::{FPDF_ANNOT_HIGHLIGHT, FPDF_ANNOT_SQUARE},
+        test_util::filled_rect,
+        PdfiumMatrix, PdfiumPage, PdfiumPageRotation, PdfiumRect, PdfiumRenderConfig,
+        PdfiumResizeMode,
+    };
+
+    fn center_pixel_bgra(page: &PdfiumPage) -> Vec<u8> {
+        let config = PdfiumRenderConfig::new().with_width(100);
+        let bitmap = page.render(&config).unwrap();
+        let offset = (50 * bitmap.stride() + 50 * 4) as usize;
+        bitmap.as_raw_bytes()[offset..offset + 4].to_vec()
+    }
+
+    #[test]
+    fn test_insert_object_at() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+
+        // Red square covering the whole page
+        page.insert_object_at(filled_rect(0.0, 0.0, 100.0, 100.0, 255, 0, 0), 0)
+            .unwrap();
+
+        // Blue square inserted at index 0 is drawn behind the red one
+        page.insert_object_at(filled_rect(25.0, 25.0, 50.0, 50.0, 0, 0, 255), 0)
+            .unwrap();
+        assert_eq!(page.object_count(), 2);
+        assert_eq!(center_pixel_bgra(&page), vec![0, 0, 255, 255]);
+
+        // Green square appended is drawn on top of everything
+        page.insert_object_at(
+            filled_rect(25.0, 25.0, 50.0, 50.0, 0, 255, 0),
+            page.object_count(),
+        )
+        .unwrap();
+        assert_eq!(page.object_count(), 3);
+        assert_eq!(center_pixel_bgra(&page), vec![0, 255, 0, 255]);
+
+        // Out of range
+        let rect = filled_rect(0.0, 0.0, 10.0, 10.0, 0, 0, 0);
+        assert!(page.insert_object_at(rect.clone(), -1).is_err());
+        assert!(page.insert_object_at(rect, 4).is_err());
+    }
+
+    fn fill_color(document: &PdfiumDocument) -> (u32, u32, u32) {
+        let object = document.page(0).unwrap().object(0).unwrap();
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        object
+            .get_fill_color(&mut r, &mut g, &mut b, &mut a)
+            .unwrap();
+        (r, g, b)
+    }
+
+    fn red_square_document() -> PdfiumDocument {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        page.insert_object_at(filled_rect(0.0, 0.0, 100.0, 100.0, 255, 0, 0), 0)
+            .unwrap();
+        page.generate_content().unwrap();
+        let bytes = document.save_to_bytes(None).unwrap();
+        PdfiumDocument::new_from_reader(std::io::Cursor::new(bytes), None).unwrap()
+    }
+
+    #[test]
+    fn test_edit_persists_without_generate_content() {
+        let document = red_square_document();
+        assert_eq!(fill_color(&document), (255, 0, 0));
+
+        let page = document.page(0).unwrap();
+        assert!(!page.is_dirty());
+        page.object(0)
+            .unwrap()
+            .set_fill_color(0, 0, 255, 255)
+            .unwrap();
+        assert!(page.is_dirty());
+        drop(page);
+
+        let bytes = document.save_to_bytes(None).unwrap();
+        let saved = PdfiumDocument::new_from_reader(std::io::Cursor::new(bytes), None).unwrap();
+        assert_eq!(fill_color(&saved), (0, 0, 255));
+    }
+
+    #[test]
+    fn test_new_page_edits_persist_without_generate_content() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        page.insert_object_at(filled_rect(0.0, 0.0, 100.0, 100.0, 255, 0, 0), 0)
+            .unwrap();
+        assert!(page.is_dirty());
+        page.generate_content().unwrap();
+        page.object(0)
+            .unwrap()
+            .set_fill_color(0, 0, 255, 255)
+            .unwrap();
+        assert!(page.is_dirty());
+        drop(page);
+
+        let bytes = document.save_to_bytes(None).unwrap();
+        let saved = PdfiumDocument::new_from_reader(std::io::Cursor::new(bytes), None).unwrap();
+        assert_eq!(fill_color(&saved), (0, 0, 255));
+    }
+
+    #[test]
+    fn test_edit_without_auto_generate_content() {
+        let document = red_square_document();
+        document.set_auto_generate_content(false);
+
+        let page = document.page(0).unwrap();
+        page.object(0)
+            .unwrap()
+            .set_fill_color(0, 0, 255, 255)
+            .unwrap();
+        assert!(!page.is_dirty());
+        drop(page);
+
+        let bytes = document.save_to_bytes(None).unwrap();
+        let saved = PdfiumDocument::new_from_reader(std::io::Cursor::new(bytes), None).unwrap();
+        assert_eq!(fill_color(&saved), (255, 0, 0));
+    }
+
+    #[test]
+    fn test_transform_content() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        page.insert_object_at(filled_rect(0.0, 30.0, 40.0, 40.0, 255, 0, 0), 0)
+            .unwrap();
+        assert_eq!(center_pixel_bgra(&page), vec![0, 0, 0, 0]);
+
+        let matrix = PdfiumMatrix::new(1.0, 0.0, 0.0, 1.0, 50.0, 0.0);
+        page.transform_content(&matrix).unwrap();
+        assert_eq!(center_pixel_bgra(&page), vec![0, 0, 255, 255]);
+        assert!(!page.is_dirty());
+    }
+
+    #[test]
+    fn test_resize_to() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 595.0, 842.0).unwrap();
+        let rect = filled_rect(0.0, 0.0, 595.0, 842.0, 0, 0, 0);
+        page.insert_object_at(rect, 0).unwrap();
+
+        page.resize_to(612.0, 792.0, PdfiumResizeMode::FitKeepAspect)
+            .unwrap();
+
+        let media = page.boundaries().media().unwrap();
+        assert_eq!((media.width(), media.height()), (612.0, 792.0));
+        let crop = page.boundaries().crop().unwrap();
+        assert_eq!((crop.width(), crop.height()), (612.0, 792.0));
+
+        // Height is the limiting dimension, so the content is centered horizontally
+        let (mut left, mut bottom, mut right, mut top) = (0.0, 0.0, 0.0, 0.0);
+        page.object(0)
+            .unwrap()
+            .get_bounds(&mut left, &mut bottom, &mut right, &mut top)
+            .unwrap();
+        let margin = (612.0 - 595.0 * 792.0 / 842.0) / 2.0;
+        assert!((left - margin).abs() < 0.1);
+        assert!((612.0 - right - margin).abs() < 0.1);
+        assert!(bottom.abs() < 0.1);
+        assert!((top - 792.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_is_empty_text() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        assert!(!document.page(0).unwrap().is_empty_text().unwrap());
+
+        // Second page of this book is a full page image
+        let document =
+            PdfiumDocument::new_from_path("resources/pg1342-images-3.pdf", None).unwrap();
+        assert!(document.page(1).unwrap().is_empty_text().unwrap());
+        assert!(!document.page(3).unwrap().is_empty_text().unwrap());
+    }
+
+    #[test]
+    fn test_object_with_mcid() {
+        let document = PdfiumDocument::new_from_path("resources/tagged_table.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let object = page.object_with_mcid(0).unwrap();
+        assert_eq!(object.get_type(), ObjectType::Text);
+        assert!(page.object_with_mcid(1).is_none());
+    }
 
     #[test]
     fn test_sequential_page_access() {
@@ -141,4 +755,187 @@ mod tests {
         let page = document.page(2);
         assert!(page.is_err());
     }
+
+    #[test]
+    fn test_annotation_at() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 200.0, 200.0).unwrap();
+
+        let square = lib()
+            .FPDFPage_CreateAnnot(&page, FPDF_ANNOT_SQUARE)
+            .unwrap();
+        let rect = PdfiumRect::new_from_lbrt((10.0, 10.0, 110.0, 110.0));
+        lib().FPDFAnnot_SetRect(&square, &(&rect).into()).unwrap();
+
+        let highlight = lib()
+            .FPDFPage_CreateAnnot(&page, FPDF_ANNOT_HIGHLIGHT)
+            .unwrap();
+        let rect = PdfiumRect::new_from_lbrt((50.0, 50.0, 150.0, 150.0));
+        lib()
+            .FPDFAnnot_SetRect(&highlight, &(&rect).into())
+            .unwrap();
+
+        assert_eq!(page.annotation_count(), 2);
+
+        let hit = page.annotation_at(20.0, 20.0).unwrap();
+        assert_eq!(hit.subtype(), FPDF_ANNOT_SQUARE);
+
+        // Overlapping area: the highlight was added last and is on top
+        let hit = page.annotation_at(75.0, 75.0).unwrap();
+        assert_eq!(hit.subtype(), FPDF_ANNOT_HIGHLIGHT);
+
+        assert!(page.annotation_at(180.0, 20.0).is_none());
+    }
+
+    #[test]
+    fn test_rasterize() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        assert!(page.text().unwrap().char_count().unwrap() > 0);
+        assert!(page.rasterize(0.0).is_err());
+
+        page.rasterize(36.0).unwrap();
+        drop(page);
+        let bytes = document.save_to_bytes(None).unwrap();
+
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        let page = document.page(0).unwrap();
+        assert_eq!(page.object_count(), 1);
+        assert_eq!(page.object(0).unwrap().get_type(), ObjectType::Image);
+        assert_eq!(page.text().unwrap().char_count().unwrap(), 0);
+
+        let bounds = page.boundaries().default().unwrap();
+        let (mut width, mut height) = (0, 0);
+        lib()
+            .FPDFImageObj_GetImagePixelSize(&page.object(0).unwrap(), &mut width, &mut height)
+            .unwrap();
+        assert_eq!(width, (bounds.width() / 2.0).round() as u32);
+        assert_eq!(height, (bounds.height() / 2.0).round() as u32);
+    }
+
+    #[test]
+    fn test_rasterize_rotated() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let bounds = page.boundaries().default().unwrap();
+        page.set_rotation(PdfiumPageRotation::Clockwise90);
+        page.rasterize(36.0).unwrap();
+        assert_eq!(page.rotation(), PdfiumPageRotation::None);
+        drop(page);
+        let bytes = document.save_to_bytes(None).unwrap();
+
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        let page = document.page(0).unwrap();
+        assert_eq!(page.rotation(), PdfiumPageRotation::None);
+        assert!((page.width() - bounds.height()).abs() < 0.01);
+        assert!((page.height() - bounds.width()).abs() < 0.01);
+
+        let (mut width, mut height) = (0, 0);
+        lib()
+            .FPDFImageObj_GetImagePixelSize(&page.object(0).unwrap(), &mut width, &mut height)
+            .unwrap();
+        assert_eq!(width, (bounds.height() / 2.0).round() as u32);
+        assert_eq!(height, (bounds.width() / 2.0).round() as u32);
+    }
+
+    #[test]
+    fn test_object_at() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 200.0, 200.0).unwrap();
+        let rect = filled_rect(10.0, 10.0, 180.0, 180.0, 0, 0, 0);
+        page.insert_object_at(rect, 0).unwrap();
+        let font = CString::new("Helvetica").unwrap();
+        let text = lib()
+            .FPDFPageObj_NewTextObj(&document, &font, 12.0)
+            .unwrap();
+        lib().FPDFText_SetText(&text, "On top").unwrap();
+        text.transform(1.0, 0.0, 0.0, 1.0, 20.0, 100.0);
+        page.insert_object_at(text, 1).unwrap();
+
+        let text_bounds = page.object(1).unwrap().bounds().unwrap();
+        let (x, y) = (
+            (text_bounds.left + text_bounds.right) / 2.0,
+            (text_bounds.bottom + text_bounds.top) / 2.0,
+        );
+        let hit = page.object_at(x, y).unwrap();
+        assert_eq!(hit.get_type(), ObjectType::Text);
+        assert_eq!(
+            page.object_at(150.0, 30.0).unwrap().get_type(),
+            ObjectType::Path
+        );
+        assert!(page.object_at(195.0, 195.0).is_none());
+    }
+
+    #[test]
+    fn test_objects_with_bounds() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let pairs: Vec<_> = page.objects_with_bounds().collect();
+        assert!(!pairs.is_empty());
+        assert_eq!(pairs.len() as i32, page.object_count());
+        for (object, bounds) in &pairs {
+            assert!(bounds.width() > 0.0 || bounds.height() > 0.0);
+            assert_eq!(object.bounds().unwrap().left, bounds.left);
+        }
+    }
+
+    #[test]
+    fn test_describe_objects() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        assert_eq!(page.describe_objects().unwrap(), "");
+
+        page.insert_object_at(filled_rect(10.0, 10.0, 20.0, 20.0, 255, 0, 0), 0)
+            .unwrap();
+        let image = lib().FPDFPageObj_NewImageObj(&document).unwrap();
+        image
+            .set_matrix(&(&PdfiumMatrix::new(40.0, 0.0, 0.0, 20.0, 50.0, 60.0)).into())
+            .unwrap();
+        page.insert_object_at(image, 1).unwrap();
+
+        let dump = page.describe_objects().unwrap();
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "0: Path bounds=[10.00, 10.00, 30.00, 30.00] \
+                 matrix=[1.00, 0.00, 0.00, 1.00, 0.00, 0.00]",
+                "1: Image bounds=[50.00, 60.00, 90.00, 80.00] \
+                 matrix=[40.00, 0.00, 0.00, 20.00, 50.00, 60.00]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_page_size_and_rotation() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 200.0, 100.0).unwrap();
+        assert_eq!((page.width(), page.height()), (200.0, 100.0));
+        assert_eq!(page.rotation(), PdfiumPageRotation::None);
+
+        page.set_rotation(PdfiumPageRotation::Clockwise90);
+        assert_eq!(page.rotation(), PdfiumPageRotation::Clockwise90);
+        drop(page);
+
+        let bytes = document.save_to_bytes(None).unwrap();
+        let document = PdfiumDocument::new_from_reader(Cursor::new(bytes), None).unwrap();
+        let page = document.page(0).unwrap();
+        assert_eq!(page.rotation(), PdfiumPageRotation::Clockwise90);
+        assert_eq!((page.width(), page.height()), (100.0, 200.0));
+
+        page.set_rotation(PdfiumPageRotation::Clockwise270);
+        assert_eq!(page.rotation(), PdfiumPageRotation::Clockwise270);
+    }
+
+    #[test]
+    fn test_page_rotation_conversion() {
+        for value in 0..4 {
+            assert_eq!(i32::from(PdfiumPageRotation::from(value)), value);
+        }
+        assert_eq!(PdfiumPageRotation::from(5), PdfiumPageRotation::Clockwise90);
+        assert_eq!(
+            PdfiumPageRotation::from(-1),
+            PdfiumPageRotation::Clockwise270
+        );
+    }
 }
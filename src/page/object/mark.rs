@@ -17,8 +17,12 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::ffi::CString;
+
 use crate::{
+    c_api::utf16::read_utf16,
     error::{PdfiumError, PdfiumResult},
+    lib,
     pdfium_types::{Handle, PageObjectMarkHandle, FPDF_PAGEOBJECTMARK},
 };
 
@@ -38,6 +42,57 @@ impl PdfiumPageObjectMark {
             })
         }
     }
+
+    /// Returns the name (tag) of this [`PdfiumPageObjectMark`].
+    pub fn name(&self) -> PdfiumResult<String> {
+        read_utf16(|buffer, buflen| {
+            let mut len = 0;
+            lib()
+                .FPDFPageObjMark_GetName(self, buffer, buflen, &mut len)
+                .map_or(0, |_| len)
+        })
+        .ok_or(PdfiumError::InvokationFailed)
+    }
+
+    /// Returns the number of properties of this [`PdfiumPageObjectMark`], or -1 on failure.
+    pub fn param_count(&self) -> i32 {
+        lib().FPDFPageObjMark_CountParams(self)
+    }
+
+    /// Returns the value of the integer property `key` of this [`PdfiumPageObjectMark`].
+    ///
+    /// Fails if the property does not exist or is not an integer.
+    pub fn int_param(&self, key: &CString) -> PdfiumResult<i32> {
+        let mut value = 0;
+        lib().FPDFPageObjMark_GetParamIntValue(self, key, &mut value)?;
+        Ok(value)
+    }
+
+    /// Returns the value of the string property `key` of this [`PdfiumPageObjectMark`].
+    ///
+    /// Fails if the property does not exist or is not a string.
+    pub fn string_param(&self, key: &CString) -> PdfiumResult<String> {
+        read_utf16(|buffer, buflen| {
+            let mut len = 0;
+            lib()
+                .FPDFPageObjMark_GetParamStringValue(self, key, buffer, buflen, &mut len)
+                .map_or(0, |_| len)
+        })
+        .ok_or(PdfiumError::InvokationFailed)
+    }
+
+    /// Returns the value of the blob property `key` of this [`PdfiumPageObjectMark`].
+    ///
+    /// Fails if the property does not exist or is not a blob.
+    pub fn blob_param(&self, key: &CString) -> PdfiumResult<Vec<u8>> {
+        let lib = lib();
+        let mut len = 0;
+        lib.FPDFPageObjMark_GetParamBlobValue(self, key, &mut [], 0, &mut len)?;
+        let mut buffer = vec![0u8; len as usize];
+        lib.FPDFPageObjMark_GetParamBlobValue(self, key, &mut buffer, len, &mut len)?;
+        buffer.truncate(len as usize);
+        Ok(buffer)
+    }
 }
 
 impl From<&PdfiumPageObjectMark> for FPDF_PAGEOBJECTMARK {
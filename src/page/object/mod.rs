@@ -19,6 +19,7 @@
 
 pub mod mark;
 pub mod objects;
+pub mod shading;
 
 use std::{ffi::CString, os::raw::c_ulong};
 
@@ -32,7 +33,8 @@ use crate::{
     pdfium_types::{
         Handle, PageObjectHandle, FPDF_BOOL, FPDF_PAGEOBJECT, FS_MATRIX, FS_QUADPOINTSF,
     },
-    PdfiumClipPath, PdfiumPage, PdfiumPageObjectMark,
+    PdfiumClipPath, PdfiumColor, PdfiumDocument, PdfiumMatrix, PdfiumPage, PdfiumPageObjectMark,
+    PdfiumPoint, PdfiumQuad, PdfiumRect,
 };
 
 /// # Rust interface to FPDF_PAGEOBJECT
@@ -40,6 +42,8 @@ use crate::{
 pub struct PdfiumPageObject {
     handle: PageObjectHandle,
     owner: Option<PdfiumPage>,
+    // Maps the space of the containing form object(s) onto the page, identity at top level
+    container_matrix: PdfiumMatrix,
 }
 
 impl PdfiumPageObject {
@@ -50,6 +54,7 @@ impl PdfiumPageObject {
             Ok(Self {
                 handle: Handle::new(handle, Some(close_page_object)),
                 owner: None,
+                container_matrix: PdfiumMatrix::identity(),
             })
         }
     }
@@ -58,6 +63,70 @@ impl PdfiumPageObject {
         self.owner = Some(owner);
     }
 
+    /// Records on the owning [`PdfiumPage`] that its content stream needs regenerating.
+    fn mark_dirty(&self) {
+        if let Some(owner) = &self.owner {
+            owner.mark_dirty();
+        }
+    }
+
+    /// Returns the bounding box of this [`PdfiumPageObject`], relative to its container.
+    ///
+    /// For objects inside a form object the bounds are in the space of that form object, see
+    /// [`PdfiumPageObject::page_bounds`] for bounds in page space.
+    pub fn bounds(&self) -> PdfiumResult<PdfiumRect> {
+        let (mut left, mut bottom, mut right, mut top) = (0.0, 0.0, 0.0, 0.0);
+        self.get_bounds(&mut left, &mut bottom, &mut right, &mut top)?;
+        Ok(PdfiumRect::new_from_lbrt((left, bottom, right, top)))
+    }
+
+    /// Returns the bounding box of this [`PdfiumPageObject`] in page space.
+    ///
+    /// For objects obtained through [`PdfiumPageObject::form_object`] the transforms of all
+    /// containing form objects are applied. For top-level objects this equals
+    /// [`PdfiumPageObject::bounds`].
+    pub fn page_bounds(&self) -> PdfiumResult<PdfiumRect> {
+        let bounds = self.bounds()?;
+        let corners = [
+            (bounds.left, bounds.bottom),
+            (bounds.right, bounds.bottom),
+            (bounds.right, bounds.top),
+            (bounds.left, bounds.top),
+        ]
+        .map(|(x, y)| self.container_matrix.transform_point(x, y));
+        let (mut left, mut bottom) = corners[0];
+        let (mut right, mut top) = corners[0];
+        for (x, y) in corners {
+            left = left.min(x);
+            right = right.max(x);
+            bottom = bottom.min(y);
+            top = top.max(y);
+        }
+        Ok(PdfiumRect::new_from_lbrt((left, bottom, right, top)))
+    }
+
+    /// Returns the number of objects inside this form [`PdfiumPageObject`], or -1 if this is
+    /// not a form object.
+    pub fn form_object_count(&self) -> i32 {
+        lib().FPDFFormObj_CountObjects(self)
+    }
+
+    /// Returns the [`PdfiumPageObject`] at `index` inside this form [`PdfiumPageObject`].
+    ///
+    /// The returned object remembers the transforms of its containing form objects, see
+    /// [`PdfiumPageObject::page_bounds`].
+    pub fn form_object(&self, index: i32) -> PdfiumResult<PdfiumPageObject> {
+        if index < 0 {
+            return Err(PdfiumError::InvokationFailed);
+        }
+        let mut matrix: FS_MATRIX = (&PdfiumMatrix::identity()).into();
+        self.get_matrix(&mut matrix)?;
+        let mut object = lib().FPDFFormObj_GetObject(self, index as c_ulong)?;
+        object.owner = self.owner.clone();
+        object.container_matrix = PdfiumMatrix::from(matrix).then(&self.container_matrix);
+        Ok(object)
+    }
+
     /// Removes a property from a content mark by key.
     ///
     /// page_object - handle to the page object with the mark.
@@ -66,7 +135,63 @@ impl PdfiumPageObject {
     ///
     /// Returns TRUE if the operation succeeded, FALSE otherwise.
     pub fn remove_param(&self, mark: &PdfiumPageObjectMark, key: &CString) -> PdfiumResult<()> {
-        lib().FPDFPageObjMark_RemoveParam(self, mark, key)
+        lib()
+            .FPDFPageObjMark_RemoveParam(self, mark, key)
+            .inspect(|_| self.mark_dirty())
+    }
+
+    /// Sets the integer property `key` of the content `mark` of this [`PdfiumPageObject`].
+    ///
+    /// Adds the property if it does not exist yet, and replaces its value otherwise.
+    pub fn set_mark_int_param(
+        &self,
+        mark: &PdfiumPageObjectMark,
+        key: &CString,
+        value: i32,
+    ) -> PdfiumResult<()> {
+        let document = self.document()?;
+        lib()
+            .FPDFPageObjMark_SetIntParam(&document, self, mark, key, value)
+            .inspect(|_| self.mark_dirty())
+    }
+
+    /// Sets the string property `key` of the content `mark` of this [`PdfiumPageObject`].
+    ///
+    /// Adds the property if it does not exist yet, and replaces its value otherwise.
+    pub fn set_mark_string_param(
+        &self,
+        mark: &PdfiumPageObjectMark,
+        key: &CString,
+        value: &str,
+    ) -> PdfiumResult<()> {
+        let document = self.document()?;
+        let value = CString::new(value)?;
+        lib()
+            .FPDFPageObjMark_SetStringParam(&document, self, mark, key, &value)
+            .inspect(|_| self.mark_dirty())
+    }
+
+    /// Sets the blob property `key` of the content `mark` of this [`PdfiumPageObject`].
+    ///
+    /// Adds the property if it does not exist yet, and replaces its value otherwise.
+    pub fn set_mark_blob_param(
+        &self,
+        mark: &PdfiumPageObjectMark,
+        key: &CString,
+        value: &[u8],
+    ) -> PdfiumResult<()> {
+        let document = self.document()?;
+        lib()
+            .FPDFPageObjMark_SetBlobParam(&document, self, mark, key, value, value.len() as c_ulong)
+            .inspect(|_| self.mark_dirty())
+    }
+
+    /// Returns the document holding this [`PdfiumPageObject`], required to set mark properties.
+    fn document(&self) -> PdfiumResult<PdfiumDocument> {
+        self.owner
+            .as_ref()
+            .and_then(|page| page.owner.clone())
+            .ok_or(PdfiumError::InvokationFailed)
     }
 
     /// Add a new content mark to a this [`PdfiumPageObject`].
@@ -79,7 +204,9 @@ impl PdfiumPageObject {
     /// invalid if the page object is destroyed, either directly or indirectly by
     /// unloading the page.
     pub fn add_mark(&self, name: &CString) -> PdfiumResult<PdfiumPageObjectMark> {
-        lib().FPDFPageObj_AddMark(self, name)
+        lib()
+            .FPDFPageObj_AddMark(self, name)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Get number of content marks in this [`PdfiumPageObject`].
@@ -255,9 +382,8 @@ impl PdfiumPageObject {
     /// Get the quad points that bounds this [`PdfiumPageObject`].
     ///
     /// page_object  - handle to this [`PdfiumPageObject`].
-    /// quad_points  - pointer where the quadrilateral points will be stored.
     ///
-    /// On success, returns TRUE and fills in `quad_points`.
+    /// On success, returns the quadrilateral bounding this [`PdfiumPageObject`].
     ///
     /// Similar to FPDFPageObj_GetBounds(), this returns the bounds of a page
     /// object. When the object is rotated by a non-multiple of 90 degrees, this API
@@ -266,8 +392,17 @@ impl PdfiumPageObject {
     ///
     /// Currently only works the following this [`PdfiumPageObject`] types: FPDF_PAGEOBJ_TEXT and
     /// FPDF_PAGEOBJ_IMAGE.
-    pub fn get_rotated_bounds(&self, quad_points: &mut FS_QUADPOINTSF) -> PdfiumResult<()> {
-        lib().FPDFPageObj_GetRotatedBounds(self, quad_points)
+    pub fn get_rotated_bounds(&self) -> PdfiumResult<PdfiumQuad> {
+        let zero = PdfiumPoint::zero();
+        let mut quad_points: FS_QUADPOINTSF = (&PdfiumQuad::new(zero, zero, zero, zero)).into();
+        lib().FPDFPageObj_GetRotatedBounds(self, &mut quad_points)?;
+        // PDFium fills in the corners counter-clockwise, starting at the bottom-left corner
+        Ok(PdfiumQuad::new(
+            PdfiumPoint::new(quad_points.x4, quad_points.y4),
+            PdfiumPoint::new(quad_points.x3, quad_points.y3),
+            PdfiumPoint::new(quad_points.x1, quad_points.y1),
+            PdfiumPoint::new(quad_points.x2, quad_points.y2),
+        ))
     }
 
     /// Get the stroke RGBA of this [`PdfiumPageObject`]. Range of values: 0 - 255.
@@ -326,7 +461,9 @@ impl PdfiumPageObject {
     ///
     /// Returns TRUE if the operation succeeded, FALSE if it failed.
     pub fn remove_mark(&self, mark: &PdfiumPageObjectMark) -> PdfiumResult<()> {
-        lib().FPDFPageObj_RemoveMark(self, mark)
+        lib()
+            .FPDFPageObj_RemoveMark(self, mark)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Set the blend mode of this [`PdfiumPageObject`].
@@ -338,7 +475,8 @@ impl PdfiumPageObject {
     /// Difference, Exclusion, HardLight, Hue, Lighten, Luminosity, Multiply, Normal,
     /// Overlay, Saturation, Screen, SoftLight
     pub fn set_blend_mode(&self, blend_mode: &CString) {
-        lib().FPDFPageObj_SetBlendMode(self, blend_mode)
+        lib().FPDFPageObj_SetBlendMode(self, blend_mode);
+        self.mark_dirty();
     }
 
     /// Set the line dash array of this [`PdfiumPageObject`].
@@ -355,7 +493,9 @@ impl PdfiumPageObject {
         dash_count: usize,
         phase: f32,
     ) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetDashArray(self, dash_array, dash_count, phase)
+        lib()
+            .FPDFPageObj_SetDashArray(self, dash_array, dash_count, phase)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Set the line dash phase of this [`PdfiumPageObject`].
@@ -365,7 +505,9 @@ impl PdfiumPageObject {
     ///
     /// Returns TRUE on success.
     pub fn set_dash_phase(&self, phase: f32) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetDashPhase(self, phase)
+        lib()
+            .FPDFPageObj_SetDashPhase(self, phase)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Set the fill RGBA of this [`PdfiumPageObject`]. Range of values: 0 - 255.
@@ -378,7 +520,9 @@ impl PdfiumPageObject {
     ///
     /// Returns TRUE on success.
     pub fn set_fill_color(&self, r: u32, g: u32, b: u32, a: u32) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetFillColor(self, r, g, b, a)
+        lib()
+            .FPDFPageObj_SetFillColor(self, r, g, b, a)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Sets if this [`PdfiumPageObject`] is active within page.
@@ -394,7 +538,9 @@ impl PdfiumPageObject {
     /// When `active` is false, this makes the this [`PdfiumPageObject`] be treated as if it
     /// wasn't in the document even though it is still held internally.
     pub fn set_is_active(&self, active: i32) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetIsActive(self, active)
+        lib()
+            .FPDFPageObj_SetIsActive(self, active)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Set the line cap of this [`PdfiumPageObject`].
@@ -405,7 +551,9 @@ impl PdfiumPageObject {
     /// Line cap can be one of following: FPDF_LINECAP_BUTT, FPDF_LINECAP_ROUND,
     /// FPDF_LINECAP_PROJECTING_SQUARE
     pub fn set_line_cap(&self, line_cap: i32) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetLineCap(self, line_cap)
+        lib()
+            .FPDFPageObj_SetLineCap(self, line_cap)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Set the line join of this [`PdfiumPageObject`].
@@ -416,7 +564,9 @@ impl PdfiumPageObject {
     /// Line join can be one of following: FPDF_LINEJOIN_MITER, FPDF_LINEJOIN_ROUND,
     /// FPDF_LINEJOIN_BEVEL
     pub fn set_line_join(&self, line_join: i32) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetLineJoin(self, line_join)
+        lib()
+            .FPDFPageObj_SetLineJoin(self, line_join)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Set the transform matrix of this [`PdfiumPageObject`].
@@ -431,7 +581,9 @@ impl PdfiumPageObject {
     ///
     /// Returns TRUE on success.
     pub fn set_matrix(&self, matrix: &FS_MATRIX) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetMatrix(self, matrix)
+        lib()
+            .FPDFPageObj_SetMatrix(self, matrix)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Set the stroke RGBA of this [`PdfiumPageObject`]. Range of values: 0 - 255.
@@ -444,7 +596,9 @@ impl PdfiumPageObject {
     ///
     /// Returns TRUE on success.
     pub fn set_stroke_color(&self, r: u32, g: u32, b: u32, a: u32) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetStrokeColor(self, r, g, b, a)
+        lib()
+            .FPDFPageObj_SetStrokeColor(self, r, g, b, a)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Set the stroke width of this [`PdfiumPageObject`].
@@ -454,7 +608,9 @@ impl PdfiumPageObject {
     ///
     /// Returns TRUE on success
     pub fn set_stroke_width(&self, width: f32) -> PdfiumResult<()> {
-        lib().FPDFPageObj_SetStrokeWidth(self, width)
+        lib()
+            .FPDFPageObj_SetStrokeWidth(self, width)
+            .inspect(|_| self.mark_dirty())
     }
 
     /// Transform this [`PdfiumPageObject`] by the given matrix.
@@ -472,7 +628,8 @@ impl PdfiumPageObject {
     /// `b d f`
     /// and can be used to scale, rotate, shear and translate the this [`PdfiumPageObject`].
     pub fn transform(&self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) {
-        lib().FPDFPageObj_Transform(self, a, b, c, d, e, f)
+        lib().FPDFPageObj_Transform(self, a, b, c, d, e, f);
+        self.mark_dirty();
     }
 
     /// Transform (scale, rotate, shear, move) the clip path of page object.
@@ -486,7 +643,8 @@ impl PdfiumPageObject {
     /// e  - The coefficient "e" of the matrix.
     /// f  - The coefficient "f" of the matrix.
     pub fn transform_clip_path(&self, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) {
-        lib().FPDFPageObj_TransformClipPath(self, a, b, c, d, e, f)
+        lib().FPDFPageObj_TransformClipPath(self, a, b, c, d, e, f);
+        self.mark_dirty();
     }
 
     /// Transform this [`PdfiumPageObject`] by the given matrix.
@@ -501,7 +659,64 @@ impl PdfiumPageObject {
     /// unnecessary double to float conversions, and only uses 1 parameter for the
     /// matrix. It also returns whether the operation succeeded or not.
     pub fn transform_f(&self, matrix: &FS_MATRIX) -> PdfiumResult<()> {
-        lib().FPDFPageObj_TransformF(self, matrix)
+        lib()
+            .FPDFPageObj_TransformF(self, matrix)
+            .inspect(|_| self.mark_dirty())
+    }
+
+    /// Returns the fill alpha (opacity) of this [`PdfiumPageObject`]. Range: 0 - 255.
+    pub fn fill_opacity(&self) -> PdfiumResult<u8> {
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        self.get_fill_color(&mut r, &mut g, &mut b, &mut a)?;
+        Ok(a as u8)
+    }
+
+    /// Sets the fill alpha (opacity) of this [`PdfiumPageObject`], keeping its fill color.
+    pub fn set_fill_opacity(&self, alpha: u8) -> PdfiumResult<()> {
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        self.get_fill_color(&mut r, &mut g, &mut b, &mut a)?;
+        self.set_fill_color(r, g, b, alpha as u32)
+    }
+
+    /// Sets the stroke alpha (opacity) of this [`PdfiumPageObject`], keeping its stroke color.
+    pub fn set_stroke_opacity(&self, alpha: u8) -> PdfiumResult<()> {
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        self.get_stroke_color(&mut r, &mut g, &mut b, &mut a)?;
+        self.set_stroke_color(r, g, b, alpha as u32)
+    }
+
+    /// Returns the fill color of this [`PdfiumPageObject`].
+    pub fn fill_color(&self) -> PdfiumResult<PdfiumColor> {
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        self.get_fill_color(&mut r, &mut g, &mut b, &mut a)?;
+        Ok(PdfiumColor::new(r as u8, g as u8, b as u8, a as u8))
+    }
+
+    /// Sets the fill color of this [`PdfiumPageObject`] to `color`, including its alpha.
+    pub fn set_fill_color_rgba(&self, color: PdfiumColor) -> PdfiumResult<()> {
+        self.set_fill_color(
+            color.red() as u32,
+            color.green() as u32,
+            color.blue() as u32,
+            color.alpha() as u32,
+        )
+    }
+
+    /// Returns the stroke color of this [`PdfiumPageObject`].
+    pub fn stroke_color(&self) -> PdfiumResult<PdfiumColor> {
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        self.get_stroke_color(&mut r, &mut g, &mut b, &mut a)?;
+        Ok(PdfiumColor::new(r as u8, g as u8, b as u8, a as u8))
+    }
+
+    /// Sets the stroke color of this [`PdfiumPageObject`] to `color`, including its alpha.
+    pub fn set_stroke_color_rgba(&self, color: PdfiumColor) -> PdfiumResult<()> {
+        self.set_stroke_color(
+            color.red() as u32,
+            color.green() as u32,
+            color.blue() as u32,
+            color.alpha() as u32,
+        )
     }
 }
 
@@ -556,3 +771,159 @@ impl From<ObjectType> for i32 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use crate::test_util::filled_rect;
+    use crate::*;
+
+    use super::ObjectType;
+
+    #[test]
+    fn test_mark_params() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        let rect = filled_rect(10.0, 10.0, 20.0, 20.0, 0, 0, 0);
+        page.insert_object_at(rect, 0).unwrap();
+        let object = page.object(0).unwrap();
+
+        let mark = object.add_mark(&CString::new("Custom").unwrap()).unwrap();
+        let (id, label, data) = (
+            CString::new("Id").unwrap(),
+            CString::new("Label").unwrap(),
+            CString::new("Data").unwrap(),
+        );
+        object.set_mark_int_param(&mark, &id, 42).unwrap();
+        object
+            .set_mark_string_param(&mark, &label, "Région")
+            .unwrap();
+        object
+            .set_mark_blob_param(&mark, &data, &[0, 1, 255])
+            .unwrap();
+
+        assert_eq!(mark.name().unwrap(), "Custom");
+        assert_eq!(mark.param_count(), 3);
+        assert_eq!(mark.int_param(&id).unwrap(), 42);
+        assert_eq!(mark.string_param(&label).unwrap(), "Région");
+        assert_eq!(mark.blob_param(&data).unwrap(), [0, 1, 255]);
+        assert!(mark.int_param(&label).is_err());
+
+        object.set_mark_int_param(&mark, &id, 7).unwrap();
+        assert_eq!(mark.int_param(&id).unwrap(), 7);
+        drop((mark, object, page));
+
+        // The properties are written to the saved document
+        let bytes = document.save_to_bytes(None).unwrap();
+        let saved = PdfiumDocument::new_from_reader(std::io::Cursor::new(bytes), None).unwrap();
+        let object = saved.page(0).unwrap().object(0).unwrap();
+        let mark = object.get_mark(0).unwrap();
+        assert_eq!(mark.name().unwrap(), "Custom");
+        assert_eq!(mark.int_param(&id).unwrap(), 7);
+        assert_eq!(mark.string_param(&label).unwrap(), "Région");
+
+        // Objects that are not on a page have no document to hold the properties
+        let loose = filled_rect(10.0, 10.0, 20.0, 20.0, 0, 0, 0);
+        let mark = loose.add_mark(&CString::new("Custom").unwrap()).unwrap();
+        assert!(loose.set_mark_int_param(&mark, &id, 1).is_err());
+    }
+
+    #[test]
+    fn test_page_bounds() {
+        let document = PdfiumDocument::new_from_path("resources/nested_image.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let form = page.object(0).unwrap();
+        assert_eq!(form.get_type(), ObjectType::Form);
+        assert_eq!(form.form_object_count(), 1);
+
+        let image = form.form_object(0).unwrap();
+        assert_eq!(image.get_type(), ObjectType::Image);
+        let local = image.bounds().unwrap();
+        assert_eq!(
+            (local.left, local.bottom, local.right, local.top),
+            (10.0, 20.0, 60.0, 60.0)
+        );
+        let absolute = image.page_bounds().unwrap();
+        assert_eq!(
+            (absolute.left, absolute.bottom, absolute.right, absolute.top),
+            (110.0, 220.0, 160.0, 260.0)
+        );
+
+        // Top-level objects are already in page space
+        let bounds = form.bounds().unwrap();
+        let page_bounds = form.page_bounds().unwrap();
+        assert_eq!(
+            (bounds.left, bounds.bottom, bounds.right, bounds.top),
+            (
+                page_bounds.left,
+                page_bounds.bottom,
+                page_bounds.right,
+                page_bounds.top
+            )
+        );
+    }
+
+    #[test]
+    fn test_image_bounds() {
+        // A square image centered on a 200 x 100 page, scaled to fit
+        let image = image::DynamicImage::new_rgb8(50, 50);
+        let document = PdfiumDocument::from_images(&[image], Some((200.0, 100.0))).unwrap();
+        let page = document.page(0).unwrap();
+        let object = page.object(0).unwrap();
+        assert_eq!(object.get_type(), ObjectType::Image);
+
+        let bounds = object.bounds().unwrap();
+        let close = |actual: f32, expected: f32| (actual - expected).abs() < 0.01;
+        assert!(close(bounds.left, 50.0), "{bounds:?}");
+        assert!(close(bounds.bottom, 0.0), "{bounds:?}");
+        assert!(close(bounds.right, 150.0), "{bounds:?}");
+        assert!(close(bounds.top, 100.0), "{bounds:?}");
+        assert!(close(object.page_bounds().unwrap().left, 50.0));
+    }
+
+    #[test]
+    fn test_fill_opacity() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        page.insert_object_at(filled_rect(0.0, 0.0, 100.0, 100.0, 255, 0, 0), 0)
+            .unwrap();
+        let object = page.object(0).unwrap();
+        assert_eq!(object.fill_opacity().unwrap(), 255);
+
+        object.set_fill_opacity(128).unwrap();
+        assert_eq!(object.fill_opacity().unwrap(), 128);
+        let (mut r, mut g, mut b, mut a) = (0, 0, 0, 0);
+        object
+            .get_fill_color(&mut r, &mut g, &mut b, &mut a)
+            .unwrap();
+        assert_eq!((r, g, b, a), (255, 0, 0, 128));
+
+        // Half transparent red over a blue background
+        page.generate_content().unwrap();
+        let config = PdfiumRenderConfig::new()
+            .with_width(100)
+            .with_background(PdfiumColor::BLUE);
+        let bitmap = page.render(&config).unwrap();
+        let offset = (50 * bitmap.stride() + 50 * 4) as usize;
+        let pixel = &bitmap.as_raw_bytes()[offset..offset + 4];
+        assert!(pixel[0].abs_diff(127) <= 2); // blue
+        assert_eq!(pixel[1], 0); // green
+        assert!(pixel[2].abs_diff(128) <= 2); // red
+    }
+
+    #[test]
+    fn test_fill_and_stroke_color() {
+        let path = lib()
+            .FPDFPageObj_CreateNewRect(10.0, 10.0, 20.0, 20.0)
+            .unwrap();
+        let fill = PdfiumColor::new(12, 34, 56, 128);
+        path.set_fill_color_rgba(fill).unwrap();
+        assert_eq!(path.fill_color().unwrap(), fill);
+        assert_eq!(path.fill_opacity().unwrap(), 128);
+
+        path.set_stroke_color_rgba(PdfiumColor::ORANGE).unwrap();
+        assert_eq!(path.stroke_color().unwrap(), PdfiumColor::ORANGE);
+        assert_eq!(path.fill_color().unwrap(), fill);
+    }
+}
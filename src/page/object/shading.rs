@@ -0,0 +1,82 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use crate::{
+    error::{PdfiumError, PdfiumResult},
+    page::object::{ObjectType, PdfiumPageObject},
+    pdfium_types::FS_MATRIX,
+    PdfiumMatrix, PdfiumRect,
+};
+
+/// Geometry of a shading object, see [`PdfiumPageObject::shading_info`].
+///
+/// The public PDFium API does not expose the shading dictionary, so the shading type
+/// (axial, radial, mesh, ...), its coordinates and color function are not available.
+#[derive(Debug, Copy, Clone)]
+pub struct PdfiumShadingInfo {
+    /// Area covered by the shading, in page coordinates
+    pub bounds: PdfiumRect,
+
+    /// Transformation matrix of the shading object
+    pub matrix: PdfiumMatrix,
+}
+
+impl PdfiumPageObject {
+    /// Returns the [`PdfiumShadingInfo`] of this shading [`PdfiumPageObject`].
+    ///
+    /// Returns [`PdfiumError::InvokationFailed`] if this is not a shading object.
+    pub fn shading_info(&self) -> PdfiumResult<PdfiumShadingInfo> {
+        if self.get_type() != ObjectType::Shading {
+            return Err(PdfiumError::InvokationFailed);
+        }
+        let (mut left, mut bottom, mut right, mut top) = (0.0, 0.0, 0.0, 0.0);
+        self.get_bounds(&mut left, &mut bottom, &mut right, &mut top)?;
+        let mut matrix = FS_MATRIX {
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 1.0,
+            e: 0.0,
+            f: 0.0,
+        };
+        self.get_matrix(&mut matrix)?;
+        Ok(PdfiumShadingInfo {
+            bounds: PdfiumRect::new_from_lbrt((left, bottom, right, top)),
+            matrix: matrix.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_shading_info() {
+        let document = PdfiumDocument::new_from_path("resources/gradient.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let object = page.object(0).unwrap();
+        assert_eq!(object.get_type(), page::object::ObjectType::Shading);
+
+        let info = object.shading_info().unwrap();
+        assert!((info.bounds.left - 50.0).abs() < 0.5);
+        assert!((info.bounds.bottom - 50.0).abs() < 0.5);
+        assert!((info.bounds.right - 150.0).abs() < 0.5);
+        assert!((info.bounds.top - 150.0).abs() < 0.5);
+    }
+}
@@ -22,10 +22,15 @@ use std::{cell::OnceCell, ffi::CString, os::raw::c_ulong, ptr::null};
 use crate::{lib, PdfiumDocument, PdfiumPage, PdfiumResult};
 
 /// Iterator for [`PdfiumPage`]
+///
+/// Pages can be iterated from both ends, e.g. `document.pages().rev()` yields the pages
+/// from the last to the first.
 pub struct PdfiumPages<'a> {
     doc: &'a PdfiumDocument,
     page_count: OnceCell<i32>,
     current_page: i32,
+    // Index one past the last page not yet returned by `next_back`, `None` until then
+    back_page: Option<i32>,
 }
 
 impl<'a> PdfiumPages<'a> {
@@ -34,6 +39,7 @@ impl<'a> PdfiumPages<'a> {
             doc,
             page_count: OnceCell::new(),
             current_page: 0,
+            back_page: None,
         }
     }
 
@@ -42,6 +48,10 @@ impl<'a> PdfiumPages<'a> {
         *self.page_count.get_or_init(|| self.doc.page_count())
     }
 
+    fn back_page(&self) -> i32 {
+        self.back_page.unwrap_or_else(|| self.page_count())
+    }
+
     /// Returns the [`PdfiumPage`] indicated by `index` from the [`PdfiumDocument`].
     pub fn get(&self, index: i32) -> PdfiumResult<PdfiumPage> {
         self.doc.page(index)
@@ -104,7 +114,7 @@ impl<'a> Iterator for PdfiumPages<'a> {
     type Item = PdfiumResult<PdfiumPage>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_page >= self.page_count() {
+        if self.current_page >= self.back_page() {
             None
         } else {
             let page = self.doc.page(self.current_page);
@@ -114,39 +124,33 @@ impl<'a> Iterator for PdfiumPages<'a> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let len = self.page_count() as usize;
-        let remaining = len.saturating_sub(self.current_page as usize);
+        let remaining = (self.back_page() - self.current_page).max(0) as usize;
         (remaining, Some(remaining))
     }
 
     fn count(self) -> usize {
-        self.page_count() as usize - self.current_page as usize
+        self.len()
     }
 
     fn last(mut self) -> Option<Self::Item> {
-        let len = self.page_count();
-        if len == 0 || self.current_page >= len {
-            None
-        } else {
-            self.current_page = len - 1;
-            Some(self.doc.page(self.current_page))
-        }
+        self.next_back()
     }
 }
 
 impl<'a> DoubleEndedIterator for PdfiumPages<'a> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        let len = self.page_count();
-        if self.current_page >= len {
+        let back_page = self.back_page();
+        if self.current_page >= back_page {
             None
         } else {
-            let page = self.doc.page(len - 1);
-            self.page_count = OnceCell::from(len - 1); // Update page_count to reflect last page accessed
-            Some(page)
+            self.back_page = Some(back_page - 1);
+            Some(self.doc.page(back_page - 1))
         }
     }
 }
 
+impl<'a> ExactSizeIterator for PdfiumPages<'a> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,4 +193,36 @@ mod tests {
         let page_count = document.page_count();
         assert_eq!(page_count, 7);
     }
+
+    #[test]
+    fn test_pages_rev() {
+        let document = PdfiumDocument::new().unwrap();
+        for (index, width) in [100.0, 200.0, 300.0, 400.0, 500.0].into_iter().enumerate() {
+            lib()
+                .FPDFPage_New(&document, index as i32, width, 100.0)
+                .unwrap();
+        }
+        let width =
+            |page: PdfiumResult<PdfiumPage>| page.unwrap().boundaries().media().unwrap().right;
+
+        let forward: Vec<_> = document.pages().map(&width).collect();
+        let mut reversed: Vec<_> = document.pages().rev().map(&width).collect();
+        assert_eq!(forward, [100.0, 200.0, 300.0, 400.0, 500.0]);
+        reversed.reverse();
+        assert_eq!(forward, reversed);
+
+        // Cursors from both ends meet without overlapping
+        let mut pages = document.pages();
+        assert_eq!(pages.len(), 5);
+        assert_eq!(width(pages.next().unwrap()), 100.0);
+        assert_eq!(width(pages.next_back().unwrap()), 500.0);
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages.page_count(), 5);
+        assert_eq!(width(pages.next_back().unwrap()), 400.0);
+        assert_eq!(width(pages.next().unwrap()), 200.0);
+        assert_eq!(width(pages.next().unwrap()), 300.0);
+        assert!(pages.next().is_none());
+        assert!(pages.next_back().is_none());
+        assert_eq!(pages.len(), 0);
+    }
 }
@@ -0,0 +1,189 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use crate::{
+    lib,
+    pdfium_types::{FPDF_PAGEOBJECT, FS_MATRIX},
+    PdfiumError, PdfiumMatrix, PdfiumPage, PdfiumPageObject, PdfiumRect, PdfiumResult,
+};
+
+/// Surviving text of a partially redacted text object, starting at `origin`.
+struct Run {
+    origin: (f64, f64),
+    text: String,
+}
+
+/// A text object with at least one glyph inside a redaction region.
+struct Redaction {
+    object: PdfiumPageObject,
+    runs: Vec<Run>,
+}
+
+impl PdfiumPage {
+    /// Removes the glyphs inside `regions` from the text on this [`PdfiumPage`].
+    ///
+    /// Unlike removing whole text objects, only the covered glyphs are dropped: each affected
+    /// text object is replaced by new text objects holding the runs of glyphs that survive.
+    /// A glyph is covered when the center of its character box lies inside a region.
+    ///
+    /// The page text is analysed glyph by glyph, so the cost grows with the amount of text on
+    /// the page. The new text objects reuse the font, size, orientation and fill color of the
+    /// original, but other state such as stroke color, text render mode, character and word
+    /// spacing is lost. Surviving characters that the font cannot map back to a glyph (e.g. with
+    /// some embedded subset fonts) are rendered as missing glyphs. Text inside form objects is
+    /// not redacted. The replaced text objects are destroyed, so handles to them obtained
+    /// earlier must not be used afterwards.
+    pub fn redact_text_precise(&self, regions: &[PdfiumRect]) -> PdfiumResult<()> {
+        let document = self.owner.clone().ok_or(PdfiumError::InvokationFailed)?;
+        let redactions = self.collect_redactions(regions)?;
+
+        for redaction in redactions {
+            let object = &redaction.object;
+            let raw: FPDF_PAGEOBJECT = object.into();
+            let index = (0..self.object_count())
+                .find(|&index| {
+                    self.object(index)
+                        .is_ok_and(|candidate| FPDF_PAGEOBJECT::from(&candidate) == raw)
+                })
+                .ok_or(PdfiumError::NotFound)?;
+
+            let font = lib().FPDFTextObj_GetFont(object)?;
+            let mut font_size = 0.0;
+            lib().FPDFTextObj_GetFontSize(object, &mut font_size)?;
+            let mut matrix: FS_MATRIX = (&PdfiumMatrix::new_scale(1.0)).into();
+            object.get_matrix(&mut matrix)?;
+            let (mut r, mut g, mut b, mut a) = (0, 0, 0, 255);
+            object.get_fill_color(&mut r, &mut g, &mut b, &mut a)?;
+
+            for (offset, run) in redaction.runs.iter().enumerate() {
+                let replacement = lib().FPDFPageObj_CreateTextObj(&document, &font, font_size)?;
+                lib().FPDFText_SetText(&replacement, &run.text)?;
+                replacement.set_matrix(&FS_MATRIX {
+                    e: run.origin.0 as f32,
+                    f: run.origin.1 as f32,
+                    ..matrix
+                })?;
+                replacement.set_fill_color(r, g, b, a)?;
+                self.insert_object_at(replacement, index + 1 + offset as i32)?;
+            }
+
+            lib().FPDFPage_RemoveObject(self, object)?;
+            lib().FPDFPageObj_Destroy(raw);
+        }
+        self.generate_content()
+    }
+
+    /// Groups the glyphs of this page by text object and returns the objects with covered
+    /// glyphs together with their surviving runs.
+    fn collect_redactions(&self, regions: &[PdfiumRect]) -> PdfiumResult<Vec<Redaction>> {
+        let text = self.text()?;
+        let mut redactions: Vec<(Redaction, bool)> = Vec::new();
+        let mut previous: Option<(FPDF_PAGEOBJECT, i32, bool)> = None;
+
+        for index in 0..text.char_count()? {
+            if text.is_generated(index).unwrap_or(true) {
+                previous = None;
+                continue;
+            }
+            let (Ok(object), Ok(char_box)) =
+                (text.get_text_object(index), text.get_char_box(index))
+            else {
+                previous = None;
+                continue;
+            };
+            let raw: FPDF_PAGEOBJECT = (&object).into();
+            let center_x = (char_box.left + char_box.right) / 2.0;
+            let center_y = (char_box.top + char_box.bottom) / 2.0;
+            let covered = regions
+                .iter()
+                .any(|region| region.contains(center_x, center_y));
+
+            let position = match redactions
+                .iter()
+                .position(|(redaction, _)| FPDF_PAGEOBJECT::from(&redaction.object) == raw)
+            {
+                Some(position) => position,
+                None => {
+                    redactions.push((
+                        Redaction {
+                            object,
+                            runs: Vec::new(),
+                        },
+                        false,
+                    ));
+                    redactions.len() - 1
+                }
+            };
+            let (redaction, has_covered) = &mut redactions[position];
+            *has_covered |= covered;
+
+            if !covered {
+                let continues_run = matches!(previous,
+                    Some((previous_raw, previous_index, false))
+                        if previous_raw == raw && previous_index + 1 == index);
+                let glyph = text.extract(index, 1);
+                match redaction.runs.last_mut() {
+                    Some(run) if continues_run => run.text.push_str(&glyph),
+                    _ => {
+                        let mut origin = (0.0, 0.0);
+                        text.get_char_origin(index, &mut origin.0, &mut origin.1)?;
+                        redaction.runs.push(Run {
+                            origin,
+                            text: glyph,
+                        });
+                    }
+                }
+            }
+            previous = Some((raw, index, covered));
+        }
+
+        Ok(redactions
+            .into_iter()
+            .filter_map(|(redaction, has_covered)| has_covered.then_some(redaction))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_redact_text_precise() {
+        let document = PdfiumDocument::new_from_path("resources/cropped.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let text = page.text().unwrap();
+        assert!(text.full().contains("Visible"));
+
+        // Cover "sib" in the middle of "Visible"
+        let first = text.get_char_box(2).unwrap();
+        let last = text.get_char_box(4).unwrap();
+        assert_eq!(text.extract(2, 3), "sib");
+        let region = PdfiumRect::new(first.left, first.top, last.right, last.bottom);
+        drop(text);
+
+        page.redact_text_precise(&[region]).unwrap();
+
+        let full = page.text().unwrap().full();
+        assert!(full.contains("Vi"));
+        assert!(full.contains("le"));
+        assert!(full.contains("Hidden"));
+        assert!(!full.contains('s'));
+        assert!(!full.contains('b'));
+    }
+}
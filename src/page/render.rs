@@ -17,11 +17,14 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::{ffi::c_void, time::Duration};
+
 use crate::{
     lib, pdfium_constants,
-    pdfium_types::{FS_MATRIX, FS_RECTF},
-    PdfiumBitmap, PdfiumBitmapFormat, PdfiumColor, PdfiumError, PdfiumMatrix, PdfiumPage,
-    PdfiumRect, PdfiumResult,
+    pdfium_types::{FPDF_BOOL, FS_MATRIX, FS_RECTF, IFSDK_PAUSE},
+    PdfiumBitmap, PdfiumBitmapFormat, PdfiumBorrowedBitmap, PdfiumColor, PdfiumDeadline,
+    PdfiumError, PdfiumForm, PdfiumMatrix, PdfiumPage, PdfiumPageRotation, PdfiumRect,
+    PdfiumResult,
 };
 
 use bitflags::bitflags;
@@ -126,6 +129,24 @@ impl PdfiumRenderFlags {
 /// - You must also provide either `scale` OR `matrix`
 /// - **Error**: Providing neither scaling instructions will cause an error
 ///
+/// ### Resolution mode (DPI specified)
+/// - Provide only a `dpi`, both dimensions are calculated from the page size
+/// - **Error**: Do not provide a `width`, `height`, `scale` or `matrix` in this mode
+///
+/// In all modes except with a custom `matrix`, the page can be rotated in quarter turns.
+/// Width and height then refer to the rotated bitmap.
+///
+/// ## Orientation
+///
+/// The final rotation is the explicit rotation (see [`PdfiumRenderConfig::with_rotation`])
+/// plus, when [`PdfiumRenderConfig::rotate_if_landscape`] is enabled and the media box of the
+/// unrotated page is wider than high, one more clockwise quarter turn. So a landscape page without an
+/// explicit rotation comes out portrait, and with an explicit quarter turn it comes out
+/// upside down and landscape.
+///
+/// [`PdfiumRenderConfig::resolve`] turns a configuration into the final bitmap size and
+/// matrix for a specific page. All render methods of [`PdfiumPage`] use it.
+///
 /// ## PDFium Integration
 ///
 /// All parameters are passed directly to PDFium, except for the automatic
@@ -157,7 +178,7 @@ pub struct PdfiumRenderConfig {
     height: Option<i32>,
     /// The pixel format for the rendered bitmap (BGRA, RGB, etc.).
     format: PdfiumBitmapFormat,
-    /// Background color for the bitmap. If None, background is transparent.
+    /// Background color for the bitmap. If None, background is fully transparent.
     background: Option<PdfiumColor>,
     /// Bitflags controlling various rendering behaviors and optimizations.
     flags: PdfiumRenderFlags,
@@ -169,6 +190,12 @@ pub struct PdfiumRenderConfig {
     matrix: Option<PdfiumMatrix>,
     /// Clipping rectangle to restrict rendering to a specific area of the page.
     clipping: Option<PdfiumRect>,
+    /// Target resolution in dots per inch. Cannot be combined with dimensions or scale.
+    dpi: Option<f32>,
+    /// Clockwise rotation of the page in quarter turns (0..=3).
+    rotation: i32,
+    /// Rotate landscape pages an additional quarter turn clockwise.
+    rotate_if_landscape: bool,
 }
 
 impl Default for PdfiumRenderConfig {
@@ -183,6 +210,9 @@ impl Default for PdfiumRenderConfig {
             pan: None,
             matrix: None,
             clipping: None,
+            dpi: None,
+            rotation: 0,
+            rotate_if_landscape: false,
         }
     }
 }
@@ -193,7 +223,7 @@ impl PdfiumRenderConfig {
     /// Default configuration uses BGRA format with a white background,
     /// the ANNOT and LCD_TEXT rendering flags and no clipping.
     ///
-    /// You must specify at least width or height before rendering.
+    /// You must specify at least width, height or DPI before rendering.
     pub fn new() -> Self {
         Self::default()
     }
@@ -264,6 +294,23 @@ impl PdfiumRenderConfig {
         self
     }
 
+    /// Sets an optional background color.
+    ///
+    /// - `Some(color)`: the bitmap is filled with `color` before the page is rendered
+    /// - `None`: the bitmap is initialized to fully transparent, so the page content
+    ///   can be composited over whatever the caller places it on
+    ///
+    /// Transparency is only retained by pixel formats with an alpha channel
+    /// ([`PdfiumBitmapFormat::Bgra`] and [`PdfiumBitmapFormat::BgraPremul`]).
+    /// Other formats end up with a black background when `None` is given.
+    ///
+    /// # Arguments
+    /// * `color` - The background color to use, or `None` for transparent
+    pub fn with_opt_background(mut self, color: Option<PdfiumColor>) -> Self {
+        self.background = color;
+        self
+    }
+
     /// Sets the rendering flags to control various behaviors.
     ///
     /// You can combine multiple flags using the bitwise OR operator (|).
@@ -335,18 +382,88 @@ impl PdfiumRenderConfig {
         self
     }
 
+    /// Sets the target resolution in dots per inch.
+    ///
+    /// PDF pages are measured in points (1/72 inch), so the bitmap dimensions are
+    /// calculated as `points / 72 * dpi`, rounded to the nearest pixel.
+    ///
+    /// Cannot be used with explicit dimensions, a scale or a custom transformation matrix.
+    ///
+    /// # Arguments
+    /// * `dpi` - Target resolution in dots per inch (must be > 0.0)
+    pub fn with_dpi(mut self, dpi: f32) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Rotates the rendered page clockwise by `rotation`.
+    ///
+    /// The rotation is applied before scaling and panning, so `width` and `height` refer
+    /// to the rotated bitmap. Accepts a [`PdfiumPageRotation`] or a number of clockwise
+    /// quarter turns, where negative values rotate counter-clockwise.
+    ///
+    /// Cannot be used with custom transformation matrices.
+    ///
+    /// # Arguments
+    /// * `rotation` - The clockwise rotation
+    pub fn with_rotation(mut self, rotation: impl Into<PdfiumPageRotation>) -> Self {
+        self.rotation = rotation.into().into();
+        self
+    }
+
+    /// Rotates landscape pages clockwise by 90 degrees, so they are rendered portrait.
+    ///
+    /// A page is landscape when the width of its media box exceeds its height, before any
+    /// rotation. The crop box is not considered, so a portrait crop of a landscape page is
+    /// rotated as well. The
+    /// quarter turn is added to the rotation set with [`PdfiumRenderConfig::with_rotation`],
+    /// see [Orientation](PdfiumRenderConfig#orientation).
+    ///
+    /// Cannot be used with custom transformation matrices.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether landscape pages should be rotated
+    pub fn rotate_if_landscape(mut self, enabled: bool) -> Self {
+        self.rotate_if_landscape = enabled;
+        self
+    }
+
+    /// Renders `page` with this configuration, see [`PdfiumPage::render`].
+    pub fn render(&self, page: &PdfiumPage) -> PdfiumResult<PdfiumBitmap> {
+        page.render(self)
+    }
+
     /// Validates the configuration for internal consistency.
     ///
     /// This method checks for conflicting or impossible parameter combinations
     /// and returns descriptive error messages for invalid configurations.
     pub fn validate(&self) -> PdfiumResult<()> {
         // Check for basic dimension requirements
-        if self.width.is_none() && self.height.is_none() {
+        if self.width.is_none() && self.height.is_none() && self.dpi.is_none() {
             return Err(PdfiumError::InvalidConfiguration(
-                "At least width or height must be specified".to_string(),
+                "At least width or height must be specified, or a DPI".to_string(),
             ));
         }
 
+        // Check DPI parameter validity and conflicts
+        if let Some(dpi) = self.dpi {
+            if dpi <= 0.0 || !dpi.is_finite() {
+                return Err(PdfiumError::InvalidConfiguration(
+                    "DPI must be positive".to_string(),
+                ));
+            }
+            if self.width.is_some() || self.height.is_some() {
+                return Err(PdfiumError::InvalidConfiguration(
+                    "Cannot specify both DPI and width or height".to_string(),
+                ));
+            }
+            if self.matrix.is_some() || self.scale.is_some() {
+                return Err(PdfiumError::InvalidConfiguration(
+                    "Cannot specify DPI together with matrix or scale".to_string(),
+                ));
+            }
+        }
+
         // Check for positive dimensions for width
         if let Some(w) = self.width {
             if w <= 0 {
@@ -387,6 +504,12 @@ impl PdfiumRenderConfig {
             ));
         }
 
+        if self.matrix.is_some() && (self.rotation != 0 || self.rotate_if_landscape) {
+            return Err(PdfiumError::InvalidConfiguration(
+                "Cannot specify both matrix and rotation parameters".to_string(),
+            ));
+        }
+
         // Check for dimension/transformation compatibility
         if self.width.is_some()
             && self.height.is_some()
@@ -401,6 +524,145 @@ impl PdfiumRenderConfig {
 
         Ok(())
     }
+
+    /// Resolves this configuration for `page` into the final rendering parameters.
+    ///
+    /// Validates the configuration, calculates the missing dimension(s) from the page size
+    /// and combines rotation, scale and pan into a single matrix. This is exactly what
+    /// [`PdfiumPage::render`] passes to PDFium.
+    pub fn resolve(&self, page: &PdfiumPage) -> PdfiumResult<PdfiumResolvedRender> {
+        let boundaries = page.boundaries();
+        let bounds = boundaries.default()?;
+        let media = boundaries.media().or_else(|_| boundaries.default())?;
+        self.resolve_for_size(
+            bounds.width(),
+            bounds.height(),
+            media.width() > media.height(),
+        )
+    }
+
+    /// Resolves this configuration for a page of `page_width` by `page_height` points, whose
+    /// media box is `landscape` or not.
+    fn resolve_for_size(
+        &self,
+        page_width: f32,
+        page_height: f32,
+        landscape: bool,
+    ) -> PdfiumResult<PdfiumResolvedRender> {
+        self.validate()?;
+
+        // Rotation within the page space, keeping the rotated page at the origin
+        let landscape = self.rotate_if_landscape && landscape;
+        let (rotation, rotated_width, rotated_height) = match (self.rotation + landscape as i32) % 4
+        {
+            1 => (
+                PdfiumMatrix::new(0.0, 1.0, -1.0, 0.0, page_height, 0.0),
+                page_height,
+                page_width,
+            ),
+            2 => (
+                PdfiumMatrix::new(-1.0, 0.0, 0.0, -1.0, page_width, page_height),
+                page_width,
+                page_height,
+            ),
+            3 => (
+                PdfiumMatrix::new(0.0, -1.0, 1.0, 0.0, 0.0, page_width),
+                page_height,
+                page_width,
+            ),
+            _ => (PdfiumMatrix::identity(), page_width, page_height),
+        };
+        let scaled = |scale: f32| rotation.then(&PdfiumMatrix::new_scale_opt_pan(scale, self.pan));
+
+        let (width, height, matrix) = match (self.dpi, self.width, self.height) {
+            (Some(dpi), _, _) => {
+                // Resolution specified, calculate both dimensions
+                let scale = dpi / 72.0;
+                let w = (rotated_width * scale).round() as i32;
+                let h = (rotated_height * scale).round() as i32;
+                (w, h, scaled(scale))
+            }
+            (None, None, None) => {
+                // This should be caught by validate(), but just in case
+                return Err(PdfiumError::InvalidConfiguration(
+                    "At least width or height needs to be specified".to_string(),
+                ));
+            }
+            (None, None, Some(h)) => {
+                // Height specified, calculate width from aspect ratio
+                if self.matrix.is_some() || self.scale.is_some() {
+                    return Err(PdfiumError::InvalidConfiguration(
+                        "Cannot specify matrix or scale when only height is provided".to_string(),
+                    ));
+                }
+                let scale = h as f32 / rotated_height;
+                let w = (rotated_width * scale) as i32;
+                (w, h, scaled(scale))
+            }
+            (None, Some(w), None) => {
+                // Width specified, calculate height from aspect ratio
+                if self.matrix.is_some() || self.scale.is_some() {
+                    return Err(PdfiumError::InvalidConfiguration(
+                        "Cannot specify matrix or scale when only width is provided".to_string(),
+                    ));
+                }
+                let scale = w as f32 / rotated_width;
+                let h = (rotated_height * scale) as i32;
+                (w, h, scaled(scale))
+            }
+            (None, Some(w), Some(h)) => {
+                // Both dimensions specified, need explicit transformation
+                let m = match (self.matrix, self.scale) {
+                    (None, Some(s)) => scaled(s),
+                    (Some(m), None) => m,
+                    // Other combinations are rejected by validate()
+                    _ => {
+                        return Err(PdfiumError::InvalidConfiguration(
+                            "When both width and height are specified, either scale or matrix must be provided"
+                                .to_string(),
+                        ))
+                    }
+                };
+                (w, h, m)
+            }
+        };
+
+        // Default to the full bitmap if no clipping is specified
+        let clipping =
+            self.clipping
+                .unwrap_or(PdfiumRect::new(0.0, 0.0, width as f32, height as f32));
+
+        Ok(PdfiumResolvedRender {
+            width,
+            height,
+            matrix,
+            clipping,
+            format: self.format,
+            flags: self.flags,
+            background: self.background,
+        })
+    }
+}
+
+/// Final rendering parameters of a [`PdfiumRenderConfig`] for a specific page
+///
+/// See [`PdfiumRenderConfig::resolve`].
+#[derive(Debug, Clone)]
+pub struct PdfiumResolvedRender {
+    /// Bitmap width in pixels
+    pub width: i32,
+    /// Bitmap height in pixels
+    pub height: i32,
+    /// Transformation from page space to bitmap pixels
+    pub matrix: PdfiumMatrix,
+    /// Clipping rectangle in bitmap pixels
+    pub clipping: PdfiumRect,
+    /// Pixel format of the bitmap
+    pub format: PdfiumBitmapFormat,
+    /// Rendering flags
+    pub flags: PdfiumRenderFlags,
+    /// Background color, `None` for transparent
+    pub background: Option<PdfiumColor>,
 }
 
 impl PdfiumPage {
@@ -424,113 +686,272 @@ impl PdfiumPage {
     /// - Memory allocation failures
     /// - Page boundary calculation errors
     pub fn render(&self, config: &PdfiumRenderConfig) -> PdfiumResult<PdfiumBitmap> {
-        // Validate configuration first
-        config.validate()?;
-
-        // Calculate final dimensions and transformation matrix
-        let (width, height, matrix) = self.calculate_render_parameters(config)?;
+        // Validate configuration and calculate final dimensions and transformation matrix
+        let resolved = config.resolve(self)?;
 
         // Create the target bitmap
-        let bitmap = PdfiumBitmap::empty(width, height, config.format)?;
+        let bitmap = PdfiumBitmap::empty(resolved.width, resolved.height, resolved.format)?;
+        self.draw(&bitmap, &resolved)?;
+        Ok(bitmap)
+    }
 
-        // Fill background if specified
-        if let Some(color) = config.background {
-            bitmap.fill(&color)?;
-        };
+    /// Renders this [`PdfiumPage`] into `bitmap`, which is backed by a buffer of the caller.
+    ///
+    /// Like [`PdfiumPage::render`], but no bitmap is allocated, see
+    /// [`PdfiumBitmap::from_external_buffer`]. The pixel format of `bitmap` is used, whatever
+    /// the format in `config`. Returns [`PdfiumError::InvalidConfiguration`] if the size
+    /// resolved from `config` differs from the size of `bitmap`.
+    pub fn render_into(
+        &self,
+        bitmap: &PdfiumBorrowedBitmap,
+        config: &PdfiumRenderConfig,
+    ) -> PdfiumResult<()> {
+        let resolved = config.resolve(self)?;
+        if resolved.width != bitmap.width() || resolved.height != bitmap.height() {
+            return Err(PdfiumError::InvalidConfiguration(
+                "Rendered size must match the size of the bitmap".to_string(),
+            ));
+        }
+        self.draw(bitmap.bitmap(), &resolved)
+    }
 
-        // Set up clipping rectangle (default to full bitmap if not specified)
-        let clipping =
-            config
-                .clipping
-                .unwrap_or(PdfiumRect::new(0.0, 0.0, width as f32, height as f32));
+    /// Clears `bitmap` to the resolved background and renders this [`PdfiumPage`] into it.
+    fn draw(&self, bitmap: &PdfiumBitmap, resolved: &PdfiumResolvedRender) -> PdfiumResult<()> {
+        // Fill background, or clear it to fully transparent if not specified
+        bitmap.fill(&resolved.background.unwrap_or(PdfiumColor::TRANSPARENT))?;
 
         // Convert to PDFium types ...
-        let clipping: FS_RECTF = (&clipping).into();
-        let matrix: FS_MATRIX = (&matrix).into();
+        let clipping: FS_RECTF = (&resolved.clipping).into();
+        let matrix: FS_MATRIX = (&resolved.matrix).into();
 
         // ... and render
         lib().FPDF_RenderPageBitmapWithMatrix(
-            &bitmap,
+            bitmap,
             self,
             &matrix,
             &clipping,
+            resolved.flags.bits(),
+        );
+        Ok(())
+    }
+
+    /// Renders this [`PdfiumPage`] including its interactive form fields.
+    ///
+    /// The page is rendered as with [`PdfiumPage::render`], after which the appearances of
+    /// the form fields in `form` are drawn on top using `FPDF_FFLDraw`. Without this, the
+    /// values of filled in form fields may not show up in the rendered bitmap.
+    ///
+    /// Form fields can only be drawn for configurations that scale and pan the page.
+    /// Returns [`PdfiumError::InvalidConfiguration`] if the matrix rotates or skews the page.
+    pub fn render_with_form(
+        &self,
+        form: &PdfiumForm,
+        config: &PdfiumRenderConfig,
+    ) -> PdfiumResult<PdfiumBitmap> {
+        let (start_x, start_y, size_x, size_y) = self.scale_pan_placement(config, "Form fields")?;
+
+        let bitmap = self.render(config)?;
+
+        lib().FPDF_FFLDraw(
+            form,
+            &bitmap,
+            self,
+            start_x,
+            start_y,
+            size_x,
+            size_y,
+            0,
             config.flags.bits(),
         );
 
         Ok(bitmap)
     }
 
-    /// Calculates the final rendering parameters (width, height, matrix) from the configuration.
+    /// Renders this [`PdfiumPage`] with the page-space rectangles `rects` highlighted.
     ///
-    /// This internal method handles the complex logic of determining final dimensions
-    /// and transformation based on the various configuration options.
-    fn calculate_render_parameters(
+    /// The page is rendered as with [`PdfiumPage::render`], after which the rectangles are
+    /// filled with `color`, composited over the content according to its alpha. Use a
+    /// semi-transparent color to get a "selected text" look, for instance with the rectangles
+    /// from [`PdfiumTextPage::find_all`](crate::PdfiumTextPage::find_all). The document itself
+    /// is not modified.
+    ///
+    /// Highlights can only be drawn for configurations that scale and pan the page.
+    /// Returns [`PdfiumError::InvalidConfiguration`] if the matrix rotates or skews the page.
+    pub fn render_with_highlights(
         &self,
+        rects: &[PdfiumRect],
+        color: PdfiumColor,
         config: &PdfiumRenderConfig,
-    ) -> PdfiumResult<(i32, i32, PdfiumMatrix)> {
-        match (config.width, config.height) {
-            (None, None) => {
-                // This should be caught by validate(), but just in case
-                Err(PdfiumError::InvalidConfiguration(
-                    "At least width or height needs to be specified".to_string(),
-                ))
-            }
-            (None, Some(h)) => {
-                // Height specified, calculate width from aspect ratio
-                if config.matrix.is_some() || config.scale.is_some() {
-                    return Err(PdfiumError::InvalidConfiguration(
-                        "Cannot specify matrix or scale when only height is provided".to_string(),
-                    ));
-                }
-                let bounds = self.boundaries().default()?;
-                let scale = h as f32 / bounds.height();
-                let w = (bounds.width() * scale) as i32;
-                let m = PdfiumMatrix::new_scale_opt_pan(scale, config.pan);
-                Ok((w, h, m))
-            }
-            (Some(w), None) => {
-                // Width specified, calculate height from aspect ratio
-                if config.matrix.is_some() || config.scale.is_some() {
-                    return Err(PdfiumError::InvalidConfiguration(
-                        "Cannot specify matrix or scale when only width is provided".to_string(),
-                    ));
-                }
-                let bounds = self.boundaries().default()?;
-                let scale = w as f32 / bounds.width();
-                let h = (bounds.height() * scale) as i32;
-                let m = PdfiumMatrix::new_scale_opt_pan(scale, config.pan);
-                Ok((w, h, m))
-            }
-            (Some(w), Some(h)) => {
-                // Both dimensions specified, need explicit transformation
-                let m = match (config.matrix, config.scale) {
-                    (None, None) => return Err(PdfiumError::InvalidConfiguration(
-                        "When both width and height are specified, scale or matrix must be provided"
-                            .to_string(),
-                    )),
-                    (None, Some(s)) => PdfiumMatrix::new_scale_opt_pan(s, config.pan),
-                    (Some(m), None) => {
-                        if config.pan.is_some() {
-                            return Err(PdfiumError::InvalidConfiguration(
-                                "Cannot specify both matrix and pan parameters".to_string(),
-                            ));
-                        };
-                        m
-                    }
-                    (Some(_), Some(_)) => {
-                        return Err(PdfiumError::InvalidConfiguration(
-                            "Cannot specify both matrix and scale parameters".to_string(),
-                        ))
-                    }
-                };
-                Ok((w, h, m))
-            }
+    ) -> PdfiumResult<PdfiumBitmap> {
+        let (start_x, start_y, size_x, size_y) = self.scale_pan_placement(config, "Highlights")?;
+
+        let mut bitmap = self.render(config)?;
+
+        let lib = lib();
+        let to_device = |x: f32, y: f32| {
+            let (mut device_x, mut device_y) = (0, 0);
+            lib.FPDF_PageToDevice(
+                self,
+                start_x,
+                start_y,
+                size_x,
+                size_y,
+                0,
+                x as f64,
+                y as f64,
+                &mut device_x,
+                &mut device_y,
+            )
+            .map(|_| (device_x, device_y))
+        };
+        for rect in rects {
+            let (x1, y1) = to_device(rect.left, rect.top)?;
+            let (x2, y2) = to_device(rect.right, rect.bottom)?;
+            bitmap.blend_rect(
+                x1.min(x2),
+                y1.min(y2),
+                x1.abs_diff(x2) as i32,
+                y1.abs_diff(y2) as i32,
+                &color,
+            )?;
         }
+
+        Ok(bitmap)
     }
+
+    /// Renders this [`PdfiumPage`] as [`PdfiumPage::render`] does, giving up after `timeout`.
+    ///
+    /// Uses the progressive render API, which pauses once the deadline has passed. In that
+    /// case rendering is abandoned and [`PdfiumError::Timeout`] is returned. This protects
+    /// servers against pathological pages that take minutes to render. PDFium only checks for
+    /// a pause between drawing operations, so a single expensive operation may overrun the
+    /// deadline.
+    ///
+    /// Only configurations that scale and pan the page are supported, without clipping.
+    /// Returns [`PdfiumError::InvalidConfiguration`] otherwise.
+    pub fn render_with_timeout(
+        &self,
+        config: &PdfiumRenderConfig,
+        timeout: Duration,
+    ) -> PdfiumResult<PdfiumBitmap> {
+        if config.clipping.is_some() {
+            return Err(PdfiumError::InvalidConfiguration(
+                "Rendering with a timeout does not support clipping".to_string(),
+            ));
+        }
+        let (start_x, start_y, size_x, size_y) =
+            self.scale_pan_placement(config, "Pages with a timeout")?;
+        let resolved = config.resolve(self)?;
+        let bitmap = PdfiumBitmap::empty(resolved.width, resolved.height, resolved.format)?;
+        bitmap.fill(&config.background.unwrap_or(PdfiumColor::TRANSPARENT))?;
+
+        let deadline = PdfiumDeadline::new(timeout);
+        let mut pause = IFSDK_PAUSE {
+            version: 1,
+            NeedToPauseNow: Some(deadline_passed),
+            user: &deadline as *const PdfiumDeadline as *mut c_void,
+        };
+
+        let lib = lib();
+        let mut status = lib.FPDF_RenderPageBitmap_Start(
+            &bitmap,
+            self,
+            start_x,
+            start_y,
+            size_x,
+            size_y,
+            0,
+            config.flags.bits(),
+            &mut pause,
+        );
+        while status == pdfium_constants::FPDF_RENDER_TOBECONTINUED && !deadline.expired() {
+            status = lib.FPDF_RenderPage_Continue(self, &mut pause);
+        }
+        lib.FPDF_RenderPage_Close(self);
+
+        match status {
+            pdfium_constants::FPDF_RENDER_DONE => Ok(bitmap),
+            pdfium_constants::FPDF_RENDER_TOBECONTINUED => Err(PdfiumError::Timeout),
+            _ => Err(PdfiumError::InvokationFailed),
+        }
+    }
+
+    /// Returns the placement (start_x, start_y, size_x, size_y) in pixels of this page for
+    /// `config`, for the PDFium APIs that do not accept a matrix.
+    ///
+    /// Returns [`PdfiumError::InvalidConfiguration`] mentioning `what` if the matrix of
+    /// `config` does more than scale and pan the page.
+    fn scale_pan_placement(
+        &self,
+        config: &PdfiumRenderConfig,
+        what: &str,
+    ) -> PdfiumResult<(i32, i32, i32, i32)> {
+        let matrix: FS_MATRIX = (&config.resolve(self)?.matrix).into();
+        if matrix.b != 0.0 || matrix.c != 0.0 || matrix.a <= 0.0 || matrix.d <= 0.0 {
+            return Err(PdfiumError::InvalidConfiguration(format!(
+                "{what} can only be rendered with a scale and pan transformation"
+            )));
+        }
+
+        let lib = lib();
+        let size_x = (lib.FPDF_GetPageWidthF(self) * matrix.a).round() as i32;
+        let size_y = (lib.FPDF_GetPageHeightF(self) * matrix.d).round() as i32;
+        Ok((
+            matrix.e.round() as i32,
+            matrix.f.round() as i32,
+            size_x,
+            size_y,
+        ))
+    }
+
+    /// Renders this [`PdfiumPage`] at the given resolution in dots per inch.
+    ///
+    /// PDF pages are measured in points (1/72 inch), so the bitmap dimensions are
+    /// calculated as `points / 72 * dpi`, rounded to the nearest pixel.
+    ///
+    /// # Arguments
+    /// * `dpi` - Target resolution in dots per inch (must be > 0)
+    /// * `format` - The pixel format for the rendered bitmap
+    /// * `background` - The background color, or `None` for a transparent background
+    /// * `flags` - Combination of PdfiumRenderFlags
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let bitmap = page.render_at_dpi(
+    ///     300.0,
+    ///     PdfiumBitmapFormat::Bgra,
+    ///     Some(PdfiumColor::WHITE),
+    ///     PdfiumRenderFlags::ANNOT,
+    /// )?;
+    /// ```
+    pub fn render_at_dpi(
+        &self,
+        dpi: f32,
+        format: PdfiumBitmapFormat,
+        background: Option<PdfiumColor>,
+        flags: PdfiumRenderFlags,
+    ) -> PdfiumResult<PdfiumBitmap> {
+        let config = PdfiumRenderConfig::new()
+            .with_dpi(dpi)
+            .with_format(format)
+            .with_opt_background(background)
+            .with_flags(flags);
+        self.render(&config)
+    }
+}
+
+/// Pause callback for [`PdfiumPage::render_with_timeout`], `user` points to the deadline.
+extern "C" fn deadline_passed(pause: *mut IFSDK_PAUSE) -> FPDF_BOOL {
+    let deadline = unsafe { &*((*pause).user as *const PdfiumDeadline) };
+    deadline.expired() as FPDF_BOOL
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
+    use crate::test_util::filled_rect;
     use crate::*;
 
     #[test]
@@ -543,6 +964,33 @@ mod tests {
         assert_eq!(bitmap.height(), 1080);
     }
 
+    #[test]
+    fn test_render_with_timeout() {
+        let document =
+            PdfiumDocument::new_from_path("resources/pg1342-images-3.pdf", None).unwrap();
+        let page = document.page(3).unwrap();
+        let config = PdfiumRenderConfig::new()
+            .with_width(1200)
+            .with_background(PdfiumColor::WHITE);
+
+        let result = page.render_with_timeout(&config, Duration::ZERO);
+        assert!(matches!(result, Err(PdfiumError::Timeout)));
+
+        let bitmap = page
+            .render_with_timeout(&config, Duration::from_secs(60))
+            .unwrap();
+        assert!(page.render_with_timeout(&config, Duration::MAX).is_ok());
+        let expected = page.render(&config).unwrap();
+        assert_eq!(bitmap.width(), expected.width());
+        assert_eq!(bitmap.height(), expected.height());
+        assert!(!bitmap.is_mostly_blank(1.0));
+
+        let config = config.with_clipping(PdfiumRect::new(0.0, 0.0, 10.0, 10.0));
+        assert!(page
+            .render_with_timeout(&config, Duration::from_secs(60))
+            .is_err());
+    }
+
     #[test]
     fn test_render_at_width() {
         let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
@@ -840,4 +1288,362 @@ mod tests {
         // Should validate successfully
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_render_transparent_background() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 100.0, 100.0).unwrap();
+        let rect = filled_rect(25.0, 25.0, 50.0, 50.0, 255, 0, 0);
+        page.insert_object_at(rect, 0).unwrap();
+
+        let config = PdfiumRenderConfig::new()
+            .with_width(100)
+            .with_opt_background(None);
+        let bitmap = page.render(&config).unwrap();
+        assert_eq!(bitmap.format(), PdfiumBitmapFormat::Bgra);
+
+        let stride = bitmap.stride() as usize;
+        let bytes = bitmap.as_raw_bytes();
+        let alpha = |x: usize, y: usize| bytes[y * stride + x * 4 + 3];
+
+        // Margins are fully transparent, content is opaque
+        assert_eq!(alpha(0, 0), 0);
+        assert_eq!(alpha(99, 99), 0);
+        assert_eq!(alpha(10, 50), 0);
+        assert_eq!(alpha(50, 50), 255);
+
+        let config = PdfiumRenderConfig::new()
+            .with_width(100)
+            .with_opt_background(Some(PdfiumColor::WHITE));
+        let bitmap = page.render(&config).unwrap();
+        assert_eq!(bitmap.as_raw_bytes()[3], 255);
+    }
+
+    #[test]
+    fn test_render_at_dpi() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let bitmap = page
+            .render_at_dpi(
+                150.0,
+                PdfiumBitmapFormat::Bgra,
+                Some(PdfiumColor::WHITE),
+                PdfiumRenderFlags::ANNOT,
+            )
+            .unwrap();
+        let bounds = page.boundaries().default().unwrap();
+        assert_eq!(
+            bitmap.width(),
+            (bounds.width() / 72.0 * 150.0).round() as i32
+        );
+        assert_eq!(
+            bitmap.height(),
+            (bounds.height() / 72.0 * 150.0).round() as i32
+        );
+
+        assert!(page
+            .render_at_dpi(
+                0.0,
+                PdfiumBitmapFormat::Bgra,
+                None,
+                PdfiumRenderFlags::empty()
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn test_render_with_form() {
+        let document = PdfiumDocument::new_from_path("resources/form.pdf", None).unwrap();
+        let form = PdfiumForm::new(&document).unwrap();
+        let page = document.page(0).unwrap();
+        let name = form.fields(&page).remove(0).annotation;
+        form.set_text(&page, &name, "Bartholomew").unwrap();
+        assert_eq!(form.field_value(&name).as_deref(), Some("Bartholomew"));
+
+        // Text field "name" is at [50 700 250 720] on a 612 x 792 page
+        let config = PdfiumRenderConfig::new()
+            .with_width(612)
+            .with_background(PdfiumColor::WHITE);
+        let field_region = |bitmap: &PdfiumBitmap| {
+            bitmap
+                .crop(50, 792 - 720, 200, 20)
+                .unwrap()
+                .as_raw_bytes()
+                .to_vec()
+        };
+        let empty = field_region(&page.render(&config).unwrap());
+        let filled = field_region(&page.render_with_form(&form, &config).unwrap());
+        assert_ne!(empty, filled);
+
+        let rotated = PdfiumRenderConfig::new()
+            .with_size(792, 612)
+            .with_matrix(PdfiumMatrix::new(0.0, 1.0, -1.0, 0.0, 792.0, 0.0));
+        assert!(page.render_with_form(&form, &rotated).is_err());
+    }
+
+    #[test]
+    fn test_render_with_highlights() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let rects = page
+            .text()
+            .unwrap()
+            .find_all("Groningen", PdfiumSearchFlags::empty())
+            .remove(0);
+        assert!(!rects.is_empty());
+
+        let bounds = page.boundaries().media().unwrap();
+        let config = PdfiumRenderConfig::new()
+            .with_width(bounds.width().round() as i32)
+            .with_format(PdfiumBitmapFormat::Bgra)
+            .with_background(PdfiumColor::WHITE);
+        let yellow = PdfiumColor::new(255, 255, 0, 128);
+        let plain = page.render(&config).unwrap();
+        let highlighted = page
+            .render_with_highlights(&rects, yellow, &config)
+            .unwrap();
+
+        // Mean blue value of the (inset) device region of the first rectangle
+        let rect = &rects[0];
+        let mean_blue = |bitmap: &PdfiumBitmap| {
+            let region = bitmap
+                .crop(
+                    (rect.left - bounds.left).ceil() as u32 + 1,
+                    (bounds.top - rect.top).ceil() as u32 + 1,
+                    rect.width().abs().floor() as u32 - 2,
+                    rect.height().abs().floor() as u32 - 2,
+                )
+                .unwrap()
+                .as_rgba_image()
+                .unwrap();
+            region.pixels().map(|p| p.0[2] as f32).sum::<f32>() / region.pixels().len() as f32
+        };
+        assert!(mean_blue(&highlighted) < mean_blue(&plain) - 50.0);
+        assert!(highlighted.similarity(&plain).unwrap() < 1.0);
+
+        let rotated = PdfiumRenderConfig::new()
+            .with_size(842, 595)
+            .with_matrix(PdfiumMatrix::new(0.0, 1.0, -1.0, 0.0, 842.0, 0.0));
+        assert!(page
+            .render_with_highlights(&rects, yellow, &rotated)
+            .is_err());
+    }
+
+    #[test]
+    fn test_resolve_modes() {
+        // Page of 600 x 800 points
+        let resolve =
+            |config: PdfiumRenderConfig| config.resolve_for_size(600.0, 800.0, false).unwrap();
+
+        let resolved = resolve(PdfiumRenderConfig::new().with_width(300));
+        assert_eq!((resolved.width, resolved.height), (300, 400));
+        assert_eq!(resolved.matrix.as_array(), [0.5, 0.0, 0.0, 0.5, 0.0, 0.0]);
+        let clipping = resolved.clipping;
+        assert_eq!(
+            (clipping.left, clipping.top, clipping.right, clipping.bottom),
+            (0.0, 0.0, 300.0, 400.0)
+        );
+
+        let resolved = resolve(
+            PdfiumRenderConfig::new()
+                .with_height(200)
+                .with_pan(5.0, 6.0),
+        );
+        assert_eq!((resolved.width, resolved.height), (150, 200));
+        assert_eq!(resolved.matrix.as_array(), [0.25, 0.0, 0.0, 0.25, 5.0, 6.0]);
+
+        let resolved = resolve(PdfiumRenderConfig::new().with_dpi(144.0));
+        assert_eq!((resolved.width, resolved.height), (1200, 1600));
+        assert_eq!(resolved.matrix.as_array(), [2.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+
+        let resolved = resolve(PdfiumRenderConfig::new().with_size(100, 50).with_scale(3.0));
+        assert_eq!((resolved.width, resolved.height), (100, 50));
+        assert_eq!(resolved.matrix.as_array(), [3.0, 0.0, 0.0, 3.0, 0.0, 0.0]);
+
+        let matrix = PdfiumMatrix::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let resolved = resolve(
+            PdfiumRenderConfig::new()
+                .with_size(10, 20)
+                .with_matrix(matrix),
+        );
+        assert_eq!(resolved.matrix.as_array(), matrix.as_array());
+
+        let clipping = PdfiumRect::new(10.0, 20.0, 30.0, 40.0);
+        let resolved = resolve(
+            PdfiumRenderConfig::new()
+                .with_width(60)
+                .with_clipping(clipping)
+                .with_transparent_background()
+                .with_flags(PdfiumRenderFlags::GRAYSCALE),
+        );
+        assert_eq!(resolved.clipping.right, clipping.right);
+        assert!(resolved.background.is_none());
+        assert_eq!(resolved.flags, PdfiumRenderFlags::GRAYSCALE);
+    }
+
+    #[test]
+    fn test_resolve_rotation() {
+        let resolve =
+            |config: PdfiumRenderConfig| config.resolve_for_size(600.0, 800.0, false).unwrap();
+        let corners = |matrix: &PdfiumMatrix| {
+            [(0.0, 0.0), (600.0, 800.0)].map(|(x, y)| matrix.transform_point(x, y))
+        };
+
+        // A quarter turn clockwise swaps the dimensions, the width refers to the rotated page
+        let resolved = resolve(PdfiumRenderConfig::new().with_width(400).with_rotation(1));
+        assert_eq!((resolved.width, resolved.height), (400, 300));
+        assert_eq!(
+            resolved.matrix.as_array(),
+            [0.0, 0.5, -0.5, 0.0, 400.0, 0.0]
+        );
+        assert_eq!(corners(&resolved.matrix), [(400.0, 0.0), (0.0, 300.0)]);
+
+        let resolved = resolve(PdfiumRenderConfig::new().with_dpi(36.0).with_rotation(2));
+        assert_eq!((resolved.width, resolved.height), (300, 400));
+        assert_eq!(corners(&resolved.matrix), [(300.0, 400.0), (0.0, 0.0)]);
+
+        let resolved = resolve(PdfiumRenderConfig::new().with_height(300).with_rotation(-1));
+        assert_eq!((resolved.width, resolved.height), (400, 300));
+        assert_eq!(corners(&resolved.matrix), [(0.0, 300.0), (400.0, 0.0)]);
+
+        let rotated = PdfiumRenderConfig::new()
+            .with_size(10, 10)
+            .with_matrix(PdfiumMatrix::identity())
+            .with_rotation(1);
+        assert!(rotated.resolve_for_size(600.0, 800.0, false).is_err());
+    }
+
+    #[test]
+    fn test_resolve_invalid_dpi() {
+        let resolve = |config: PdfiumRenderConfig| config.resolve_for_size(600.0, 800.0, false);
+        assert!(resolve(PdfiumRenderConfig::new().with_dpi(0.0)).is_err());
+        assert!(resolve(PdfiumRenderConfig::new().with_dpi(f32::NAN)).is_err());
+        assert!(resolve(PdfiumRenderConfig::new().with_dpi(72.0).with_width(100)).is_err());
+        assert!(resolve(PdfiumRenderConfig::new().with_dpi(72.0).with_scale(2.0)).is_err());
+    }
+
+    #[test]
+    fn test_resolve_page() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let bounds = page.boundaries().default().unwrap();
+
+        let resolved = PdfiumRenderConfig::new()
+            .with_dpi(150.0)
+            .resolve(&page)
+            .unwrap();
+        assert_eq!(
+            resolved.width,
+            (bounds.width() / 72.0 * 150.0).round() as i32
+        );
+        assert_eq!(
+            resolved.height,
+            (bounds.height() / 72.0 * 150.0).round() as i32
+        );
+
+        let config = PdfiumRenderConfig::new().with_height(1080).with_rotation(1);
+        let resolved = config.resolve(&page).unwrap();
+        let bitmap = page.render(&config).unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (resolved.width, 1080));
+        assert!(resolved.width > resolved.height);
+    }
+
+    #[test]
+    fn test_resolve_rotate_if_landscape() {
+        let landscape =
+            |config: PdfiumRenderConfig| config.resolve_for_size(800.0, 600.0, true).unwrap();
+
+        let resolved = landscape(
+            PdfiumRenderConfig::new()
+                .with_dpi(72.0)
+                .rotate_if_landscape(true),
+        );
+        assert_eq!((resolved.width, resolved.height), (600, 800));
+        assert_eq!(
+            resolved.matrix.as_array(),
+            [0.0, 1.0, -1.0, 0.0, 600.0, 0.0]
+        );
+
+        // Adds to an explicit rotation
+        let resolved = landscape(
+            PdfiumRenderConfig::new()
+                .with_dpi(72.0)
+                .with_rotation(1)
+                .rotate_if_landscape(true),
+        );
+        assert_eq!((resolved.width, resolved.height), (800, 600));
+        assert_eq!(
+            resolved.matrix.as_array(),
+            [-1.0, 0.0, 0.0, -1.0, 800.0, 600.0]
+        );
+
+        // Portrait pages are not rotated
+        let resolved = PdfiumRenderConfig::new()
+            .with_dpi(72.0)
+            .rotate_if_landscape(true)
+            .resolve_for_size(600.0, 800.0, false)
+            .unwrap();
+        assert_eq!((resolved.width, resolved.height), (600, 800));
+
+        let config = PdfiumRenderConfig::new()
+            .with_size(10, 10)
+            .with_matrix(PdfiumMatrix::identity())
+            .rotate_if_landscape(true);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_render_rotate_if_landscape() {
+        let document = PdfiumDocument::new().unwrap();
+        let page = lib().FPDFPage_New(&document, 0, 300.0, 100.0).unwrap();
+        let config = PdfiumRenderConfig::new().with_dpi(72.0);
+        let bitmap = page.render(&config).unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (300, 100));
+        let config = config.rotate_if_landscape(true);
+        let bitmap = page.render(&config).unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (100, 300));
+
+        // The orientation of the media box decides, not that of the crop box
+        page.boundaries()
+            .set_crop(PdfiumRect::new(0.0, 100.0, 90.0, 0.0));
+        let bitmap = page.render(&config).unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (100, 90));
+
+        let page = lib().FPDFPage_New(&document, 1, 100.0, 300.0).unwrap();
+        page.boundaries()
+            .set_crop(PdfiumRect::new(0.0, 90.0, 100.0, 0.0));
+        let bitmap = page.render(&config).unwrap();
+        assert_eq!((bitmap.width(), bitmap.height()), (100, 90));
+    }
+
+    #[test]
+    fn test_config_render() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let config = PdfiumRenderConfig::new()
+            .with_width(300)
+            .with_format(PdfiumBitmapFormat::Bgr)
+            .with_background(PdfiumColor::WHITE)
+            .with_flags(PdfiumRenderFlags::ANNOT);
+        let bitmap = config.render(&page).unwrap();
+        assert!(bitmap.pixels_equal(&page.render(&config).unwrap()).unwrap());
+
+        // A rotation is the same as the equivalent matrix
+        let rotated = config
+            .clone()
+            .with_rotation(PdfiumPageRotation::Clockwise90)
+            .render(&page)
+            .unwrap();
+        let resolved = config.clone().with_rotation(1).resolve(&page).unwrap();
+        let matrix = PdfiumRenderConfig::new()
+            .with_size(resolved.width, resolved.height)
+            .with_matrix(resolved.matrix)
+            .with_format(PdfiumBitmapFormat::Bgr)
+            .with_background(PdfiumColor::WHITE)
+            .with_flags(PdfiumRenderFlags::ANNOT);
+        assert_eq!((rotated.width(), rotated.height()), (300, resolved.height));
+        assert!(rotated
+            .pixels_equal(&page.render(&matrix).unwrap())
+            .unwrap());
+    }
 }
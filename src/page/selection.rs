@@ -0,0 +1,112 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use std::ops::Range;
+
+use crate::error::{PdfiumError, PdfiumResult};
+
+/// A selection of pages parsed from a page range string, such as "1,3,5-7".
+///
+/// Unlike [`crate::PdfiumPageRange`], which wraps a page range owned by PDFium, a
+/// [`PdfiumPageSelection`] is a plain list of page indices and is never passed to PDFium.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfiumPageSelection {
+    ranges: Vec<Range<i32>>,
+}
+
+impl PdfiumPageSelection {
+    /// Parses a page range string, such as "1,3,5-7".
+    ///
+    /// The format is the same as used by FPDF_ImportPages(): page numbers are one-based,
+    /// separated by commas, and `a-b` denotes an inclusive range. Whitespace around numbers
+    /// is ignored.
+    ///
+    /// Returns [`PdfiumError::PageError`] if the string is empty or malformed.
+    pub fn parse(range: &str) -> PdfiumResult<Self> {
+        let mut ranges = Vec::new();
+        for part in range.split(',') {
+            let (first, last) = match part.split_once('-') {
+                Some((first, last)) => (parse_page_number(first)?, parse_page_number(last)?),
+                None => {
+                    let page = parse_page_number(part)?;
+                    (page, page)
+                }
+            };
+            if first > last {
+                return Err(PdfiumError::PageError);
+            }
+            ranges.push(first - 1..last);
+        }
+        Ok(Self { ranges })
+    }
+
+    /// Returns the ranges of zero-based page indices in this [`PdfiumPageSelection`], in order.
+    pub fn ranges(&self) -> &[Range<i32>] {
+        &self.ranges
+    }
+
+    /// Returns an [`Iterator`] over the zero-based page indices in this
+    /// [`PdfiumPageSelection`], in order.
+    ///
+    /// The indices are produced lazily, as a range such as "1-2000000000" is valid but may
+    /// exceed the number of pages in a document by far. See [`PdfiumPageSelection::ranges`].
+    pub fn indices(&self) -> impl Iterator<Item = i32> + '_ {
+        self.ranges.iter().cloned().flatten()
+    }
+}
+
+fn parse_page_number(value: &str) -> PdfiumResult<i32> {
+    match value.trim().parse::<i32>() {
+        Ok(page) if page > 0 => Ok(page),
+        _ => Err(PdfiumError::PageError),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_parse_page_selection() {
+        let selection = PdfiumPageSelection::parse("1,3,5-7").unwrap();
+        assert_eq!(selection.indices().collect::<Vec<_>>(), vec![0, 2, 4, 5, 6]);
+        assert_eq!(selection.ranges(), &[0..1, 2..3, 4..7]);
+
+        let selection = PdfiumPageSelection::parse(" 2 - 3 , 10").unwrap();
+        assert_eq!(selection.indices().collect::<Vec<_>>(), vec![1, 2, 9]);
+    }
+
+    #[test]
+    fn test_parse_huge_page_selection() {
+        let selection = PdfiumPageSelection::parse("1-2000000000").unwrap();
+        assert_eq!(selection.ranges().to_vec(), vec![0..2000000000]);
+        assert_eq!(
+            selection.indices().take(3).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_page_selection() {
+        assert!(PdfiumPageSelection::parse("").is_err());
+        assert!(PdfiumPageSelection::parse("0").is_err());
+        assert!(PdfiumPageSelection::parse("3-1").is_err());
+        assert!(PdfiumPageSelection::parse("1,,2").is_err());
+        assert!(PdfiumPageSelection::parse("a-b").is_err());
+    }
+}
@@ -18,6 +18,7 @@
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 pub mod search;
+pub mod table;
 
 use std::{
     os::raw::{c_ulong, c_ushort},
@@ -25,18 +26,19 @@ use std::{
 };
 
 use crate::{
-    c_api::{i32_to_bool_result, i32_to_result},
+    c_api::{i32_to_bool_result, i32_to_result, utf16::utf16_to_string},
     error::{PdfiumError, PdfiumResult},
     lib,
     page::text::search::{PdfiumSearchFlags, PdfiumSearchIterator},
     pdfium_types::{Handle, TextPageHandle, FPDF_TEXTPAGE, FS_MATRIX, FS_RECTF},
-    PdfiumPageLink, PdfiumPageObject, PdfiumRect,
+    PdfiumPage, PdfiumPageLink, PdfiumPageObject, PdfiumRect,
 };
 
 /// # Rust interface to FPDF_TEXTPAGE
 #[derive(Debug, Clone)]
 pub struct PdfiumTextPage {
     handle: TextPageHandle,
+    owner: Option<PdfiumPage>,
 }
 
 impl PdfiumTextPage {
@@ -46,10 +48,15 @@ impl PdfiumTextPage {
         } else {
             Ok(Self {
                 handle: Handle::new(handle, Some(close_text_page)),
+                owner: None,
             })
         }
     }
 
+    pub(crate) fn set_owner(&mut self, owner: PdfiumPage) {
+        self.owner = Some(owner);
+    }
+
     /// Get information about weblinks in a page.
     ///
     /// Comments:
@@ -98,7 +105,7 @@ impl PdfiumTextPage {
         i32_to_result(lib().FPDFText_CountRects(self, start_index, count))
     }
 
-    /// Start a search.
+    /// Start a search, returning an iterator over all matches from `start_index` onwards.
     ///
     /// Parameters:
     /// * findwhat    -   A unicode match pattern.
@@ -115,6 +122,47 @@ impl PdfiumTextPage {
         }
     }
 
+    /// Searches this [`PdfiumTextPage`] for all occurrences of `query`.
+    ///
+    /// Returns, for each match, the rectangles covering the matched characters. Matches
+    /// spanning multiple lines or font runs have more than one rectangle. This is what is
+    /// needed to highlight search results on a page.
+    pub fn find_all(&self, query: &str, flags: PdfiumSearchFlags) -> Vec<Vec<PdfiumRect>> {
+        self.find(query, flags, 0)
+            .map(|result| {
+                self.rects(result.index(), result.count())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// Returns the rectangles covering `count` characters starting at `start_index`.
+    ///
+    /// Combines [`PdfiumTextPage::count_rects`] and [`PdfiumTextPage::get_rect`]. Characters
+    /// on the same line with the same font settings share a rectangle, so a run of text
+    /// spanning several lines results in (at least) one rectangle per line. Use `-1` for
+    /// `count` to include all remaining characters.
+    ///
+    /// Returns an empty vector if `count` is 0, and an error on a bad `start_index`.
+    pub fn rects(&self, start_index: i32, count: i32) -> PdfiumResult<Vec<PdfiumRect>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        let rect_count = self.count_rects(start_index, count)?;
+        (0..rect_count)
+            .map(|index| {
+                let (mut left, mut top, mut right, mut bottom) = (0.0, 0.0, 0.0, 0.0);
+                self.get_rect(index, &mut left, &mut top, &mut right, &mut bottom)?;
+                Ok(PdfiumRect::new(
+                    left as f32,
+                    top as f32,
+                    right as f32,
+                    bottom as f32,
+                ))
+            })
+            .collect()
+    }
+
     /// Function: FPDFText_GetBoundedText
     /// Extract unicode text within a rectangular boundary on the page.
     /// Parameters:
@@ -150,6 +198,52 @@ impl PdfiumTextPage {
         lib().FPDFText_GetBoundedText(self, left, top, right, bottom, buffer, buflen)
     }
 
+    /// Returns the number of UTF-16 values of text within a rectangular boundary on the page.
+    ///
+    /// This is a cheap way to check whether a region contains text, as the text itself
+    /// is not extracted.
+    ///
+    /// Parameters:
+    /// * left        -   Left boundary.
+    /// * top         -   Top boundary.
+    /// * right       -   Right boundary.
+    /// * bottom      -   Bottom boundary.
+    pub fn bounded_char_count(&self, left: f64, top: f64, right: f64, bottom: f64) -> i32 {
+        let mut buffer: c_ushort = 0;
+        // With a buffer length of zero only the number of UTF-16 values is returned
+        lib().FPDFText_GetBoundedText(self, left, top, right, bottom, &mut buffer, 0)
+    }
+
+    /// Returns the text within a rectangular boundary on the page.
+    ///
+    /// The boundaries are in PDF "user space". Returns an empty string if the rectangle
+    /// contains no text.
+    ///
+    /// Parameters:
+    /// * left        -   Left boundary.
+    /// * top         -   Top boundary.
+    /// * right       -   Right boundary.
+    /// * bottom      -   Bottom boundary.
+    pub fn text_in_rect(&self, left: f64, top: f64, right: f64, bottom: f64) -> String {
+        let count = self.bounded_char_count(left, top, right, bottom);
+        if count <= 0 {
+            return String::new();
+        }
+        // One extra value for the terminating NUL
+        let mut buffer = vec![0u16; count as usize + 1];
+        let len = buffer.len();
+        let written = lib().FPDFText_GetBoundedText(
+            self,
+            left,
+            top,
+            right,
+            bottom,
+            &mut buffer[0],
+            len as i32,
+        );
+        utf16_to_string(&buffer[..(written.max(0) as usize).min(len)])
+    }
+
     /// Get character rotation angle.
     ///
     /// Parameters:
@@ -236,6 +330,26 @@ impl PdfiumTextPage {
         lib().FPDFText_GetCharOrigin(self, index, x, y)
     }
 
+    /// Returns the boxes of all characters of this [`PdfiumTextPage`], indexed like the
+    /// characters themselves, see [`PdfiumTextPage::get_char_box`].
+    pub fn char_boxes(&self) -> PdfiumResult<Vec<PdfiumRect>> {
+        (0..self.char_count()?)
+            .map(|index| self.get_char_box(index))
+            .collect()
+    }
+
+    /// Returns the origins (x, y) of all characters of this [`PdfiumTextPage`], indexed like
+    /// the characters themselves, see [`PdfiumTextPage::get_char_origin`].
+    pub fn char_origins(&self) -> PdfiumResult<Vec<(f64, f64)>> {
+        (0..self.char_count()?)
+            .map(|index| {
+                let (mut x, mut y) = (0.0, 0.0);
+                self.get_char_origin(index, &mut x, &mut y)?;
+                Ok((x, y))
+            })
+            .collect()
+    }
+
     /// Function: FPDFText_GetFillColor
     /// Get the fill color of a particular character.
     /// Parameters:
@@ -439,8 +553,7 @@ impl PdfiumTextPage {
     /// * This function ignores characters without UCS-2 representations.
     ///   It considers all characters on the page, even those that are not
     ///   visible when the page has a cropbox. To filter out the characters
-    ///   outside of the cropbox, use FPDF_GetPageBoundingBox() and
-    ///   FPDFText_GetCharBox().
+    ///   outside of the cropbox, use [`PdfiumTextPage::full_within_cropbox`].
     pub fn extract(&self, start_index: i32, count: i32) -> String {
         if count < 1 {
             return String::default();
@@ -459,6 +572,48 @@ impl PdfiumTextPage {
         self.extract(0, self.char_count().unwrap_or_default())
     }
 
+    /// Gets the visible text of the page as string.
+    ///
+    /// Same as [`PdfiumTextPage::full`], but leaves out characters whose box lies entirely
+    /// outside the bounding box of the page (the intersection of its MediaBox and CropBox).
+    /// Generated characters (spaces and line breaks inserted by PDFium) are kept only when
+    /// the character before them is visible.
+    ///
+    /// Returns the full text if the bounding box of the page cannot be determined.
+    pub fn full_within_cropbox(&self) -> String {
+        let Some(bounds) = self
+            .owner
+            .as_ref()
+            .and_then(|page| page.boundaries().bounding_box().ok())
+        else {
+            return self.full();
+        };
+
+        let mut text = String::new();
+        let mut run_start = None;
+        let mut visible = false;
+        let count = self.char_count().unwrap_or_default();
+        for index in 0..count {
+            if !self.is_generated(index).unwrap_or(false) {
+                visible = self
+                    .get_char_box(index)
+                    .is_ok_and(|char_box| char_box.intersects(&bounds));
+            }
+            match (visible, run_start) {
+                (true, None) => run_start = Some(index),
+                (false, Some(start)) => {
+                    text.push_str(&self.extract(start, index - start));
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(start) = run_start {
+            text.push_str(&self.extract(start, count - start));
+        }
+        text
+    }
+
     /// Get the text index in this [`PdfiumTextPage`] internal character list.
     ///
     /// nCharIndex - index of the character in internal character list.
@@ -558,6 +713,25 @@ mod tests {
 
     use crate::*;
 
+    #[test]
+    fn test_char_boxes_and_origins() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let text = document.page(0).unwrap().text().unwrap();
+        let char_count = text.char_count().unwrap() as usize;
+
+        let boxes = text.char_boxes().unwrap();
+        assert_eq!(boxes.len(), char_count);
+        let origins = text.char_origins().unwrap();
+        assert_eq!(origins.len(), char_count);
+
+        let char_box = text.get_char_box(10).unwrap();
+        assert_eq!(boxes[10].left, char_box.left);
+        assert_eq!(boxes[10].top, char_box.top);
+        let (mut x, mut y) = (0.0, 0.0);
+        text.get_char_origin(10, &mut x, &mut y).unwrap();
+        assert_eq!(origins[10], (x, y));
+    }
+
     #[test]
     fn test_text_page() {
         let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
@@ -623,4 +797,160 @@ mod tests {
             assert_eq!(part, "Groningen");
         }
     }
+
+    #[test]
+    fn test_search_iterator() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let text = page.text().unwrap();
+        let full: Vec<char> = text.full().chars().collect();
+
+        let matches: Vec<PdfiumSearchResult> = text
+            .find("groningen", PdfiumSearchFlags::empty(), 0)
+            .collect();
+        assert_eq!(matches.len(), 5);
+        let mut previous = -1;
+        for result in &matches {
+            assert!(result.index() > previous);
+            assert_eq!(result.count(), 9);
+            let start = result.index() as usize;
+            let word: String = full[start..start + 9].iter().collect();
+            assert_eq!(word.to_lowercase(), "groningen");
+            previous = result.index();
+        }
+
+        // Starting after the first match skips it
+        let rest = text.find(
+            "groningen",
+            PdfiumSearchFlags::empty(),
+            matches[0].index() + 1,
+        );
+        assert_eq!(rest.collect::<Vec<_>>(), matches[1..]);
+
+        assert_eq!(
+            text.find("GRONINGEN", PdfiumSearchFlags::MATCH_CASE, 0)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_find_all() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let bounds = page.boundaries().media().unwrap();
+        let text = page.text().unwrap();
+        assert!(text
+            .find_all("amsterdam", PdfiumSearchFlags::empty())
+            .is_empty());
+        let matches = text.find_all("Groningen", PdfiumSearchFlags::MATCH_CASE);
+        assert_eq!(matches.len(), 5);
+        for rects in matches {
+            assert!(!rects.is_empty());
+            for rect in rects {
+                assert!(rect.width() > 0.0);
+                assert!(rect.height() > 0.0);
+                assert!(bounds.contains(rect.left, rect.top));
+                assert!(bounds.contains(rect.right, rect.bottom));
+            }
+        }
+    }
+
+    #[test]
+    fn test_bounded_char_count() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let bounds = page.boundaries().default().unwrap();
+        let text = page.text().unwrap();
+
+        let count = text.bounded_char_count(
+            bounds.left as f64,
+            bounds.top as f64,
+            bounds.right as f64,
+            bounds.bottom as f64,
+        );
+        assert!(count > 0);
+
+        let count = text.bounded_char_count(-200.0, -100.0, -100.0, -200.0);
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_rects() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let text = page.text().unwrap();
+
+        // The whole page spans many lines, listed from top to bottom
+        let rects = text.rects(0, -1).unwrap();
+        assert!(rects.len() > 1);
+        let bounds = page.boundaries().default().unwrap();
+        for rect in &rects {
+            assert!(rect.left >= bounds.left - 1.0 && rect.right <= bounds.right + 1.0);
+            assert!(rect.top > rect.bottom);
+        }
+        assert!(rects[0].top > rects[rects.len() - 1].top);
+
+        let hit = text
+            .find("Groningen", PdfiumSearchFlags::empty(), 0)
+            .next()
+            .unwrap();
+        let rects = text.rects(hit.index(), hit.count()).unwrap();
+        assert_eq!(rects.len(), 1);
+        assert!(text
+            .text_in_rect(
+                rects[0].left as f64,
+                rects[0].top as f64,
+                rects[0].right as f64,
+                rects[0].bottom as f64,
+            )
+            .contains("Groningen"));
+
+        assert!(text.rects(0, 0).unwrap().is_empty());
+        assert!(text.rects(-5, 1).is_err());
+    }
+
+    #[test]
+    fn test_text_in_rect() {
+        let document = PdfiumDocument::new_from_path("resources/groningen.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let text = page.text().unwrap();
+        let rect = text
+            .find_all("Groningen", PdfiumSearchFlags::empty())
+            .remove(0)
+            .remove(0);
+
+        let found = text.text_in_rect(
+            rect.left as f64 - 1.0,
+            rect.top as f64 + 1.0,
+            rect.right as f64 + 1.0,
+            rect.bottom as f64 - 1.0,
+        );
+        assert!(found.contains("Groningen"), "{found:?}");
+
+        let bounds = page.boundaries().default().unwrap();
+        let all = text.text_in_rect(
+            bounds.left as f64,
+            bounds.top as f64,
+            bounds.right as f64,
+            bounds.bottom as f64,
+        );
+        assert!(all.len() > found.len());
+
+        assert_eq!(text.text_in_rect(-200.0, -100.0, -100.0, -200.0), "");
+    }
+
+    #[test]
+    fn test_full_within_cropbox() {
+        let document = PdfiumDocument::new_from_path("resources/cropped.pdf", None).unwrap();
+        let text = document.page(0).unwrap().text().unwrap();
+
+        let full = text.full();
+        assert!(full.contains("Visible"));
+        assert!(full.contains("Hidden"));
+
+        let visible = text.full_within_cropbox();
+        assert!(visible.contains("Visible"));
+        assert!(!visible.contains("Hidden"));
+    }
 }
@@ -87,6 +87,24 @@ bitflags! {
     }
 }
 
+/// Search result with surrounding text, as returned by
+/// [`crate::PdfiumDocument::search_with_context`]
+#[derive(Debug, Clone)]
+pub struct PdfiumSearchHit {
+    /// Zero-based index of the page holding the match
+    pub page_index: i32,
+
+    /// Index of the first matched character in the text of the page
+    pub char_index: i32,
+
+    /// Matched text as it appears on the page
+    pub text: String,
+
+    /// Matched text including the surrounding text, with runs of whitespace (including line
+    /// breaks) collapsed into single spaces
+    pub snippet: String,
+}
+
 /// # Rust interface to FPDF_SCHHANDLE
 #[derive(Debug, Clone)]
 pub struct PdfiumSearch {
@@ -147,20 +165,30 @@ fn close_search(search: FPDF_SCHHANDLE) {
     lib().FPDFText_FindClose(search);
 }
 
+/// A single match, as yielded by [`PdfiumSearchIterator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PdfiumSearchResult {
     index: i32,
     count: i32,
 }
 
 impl PdfiumSearchResult {
+    /// Returns the index of the first matched character in the text page.
     pub fn index(&self) -> i32 {
         self.index
     }
+
+    /// Returns the number of matched characters.
     pub fn count(&self) -> i32 {
         self.count
     }
 }
 
+/// Iterator over the matches of a search, as returned by [`crate::PdfiumTextPage::find`]
+///
+/// Each call to `next` continues the search (`FPDFText_FindNext`) from the previous match,
+/// using the [`PdfiumSearchFlags`] the search was started with. Iteration ends when no further
+/// match is found, or immediately if the search could not be started.
 pub struct PdfiumSearchIterator {
     pub(crate) inner: PdfiumResult<PdfiumSearch>,
 }
@@ -0,0 +1,247 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{error::PdfiumResult, pdfium_types::FS_RECTF, PdfiumRect, PdfiumTextPage};
+
+/// A table detected on a page by [`PdfiumTextPage::detect_tables`]
+#[derive(Debug, Clone)]
+pub struct PdfiumTable {
+    /// The bounding box of the text in the table, in page coordinates
+    pub bounds: PdfiumRect,
+    /// The text of the cells, row by row from top to bottom, each row with one (possibly
+    /// empty) entry per column from left to right
+    pub cells: Vec<Vec<String>>,
+}
+
+impl PdfiumTable {
+    /// Returns the number of rows of this [`PdfiumTable`].
+    pub fn row_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the number of columns of this [`PdfiumTable`].
+    pub fn column_count(&self) -> usize {
+        self.cells.first().map_or(0, Vec::len)
+    }
+
+    /// Returns the text of the cell at `row` and `column`, or `None` if out of range.
+    pub fn cell(&self, row: usize, column: usize) -> Option<&str> {
+        self.cells.get(row)?.get(column).map(String::as_str)
+    }
+}
+
+/// A non-whitespace character with its loose box
+struct Glyph {
+    ch: char,
+    left: f32,
+    right: f32,
+    bottom: f32,
+    top: f32,
+}
+
+impl Glyph {
+    fn center_y(&self) -> f32 {
+        (self.bottom + self.top) / 2.0
+    }
+}
+
+/// A line of text, split into segments
+struct Line {
+    center_y: f32,
+    bottom: f32,
+    top: f32,
+    segments: Vec<Segment>,
+}
+
+/// A run of characters on a line, separated from its neighbours by a wide gap
+struct Segment {
+    left: f32,
+    right: f32,
+    text: String,
+}
+
+impl PdfiumTextPage {
+    /// Detects tables in this [`PdfiumTextPage`] from the positions of its characters.
+    ///
+    /// Characters are grouped into lines by their vertical position, and each line is split
+    /// into segments at horizontal gaps wider than twice the typical character height. The
+    /// loose character boxes are used (see [`PdfiumTextPage::get_loose_char_box`]), which
+    /// cover the advance of each character, so that gaps between words are reliable.
+    /// Consecutive lines with at least two segments form a table, whose columns are the
+    /// horizontal ranges covered by the segments of all its lines.
+    ///
+    /// This is a heuristic aimed at simple tables with aligned columns. Ruling lines are not
+    /// used, cells spanning several columns or lines are not recognized, columns whose text
+    /// overlaps horizontally are merged, and rotated text is not supported. Two-column text
+    /// layouts may be reported as tables.
+    pub fn detect_tables(&self) -> PdfiumResult<Vec<PdfiumTable>> {
+        let mut glyphs = Vec::new();
+        for index in 0..self.char_count()? {
+            let Some(ch) = char::from_u32(self.get_unicode(index)) else {
+                continue;
+            };
+            if ch.is_whitespace() || ch.is_control() {
+                continue;
+            }
+            let mut rect = FS_RECTF::from(&PdfiumRect::zero());
+            self.get_loose_char_box(index, &mut rect)?;
+            let rect = PdfiumRect::from(rect);
+            glyphs.push(Glyph {
+                ch,
+                left: rect.left.min(rect.right),
+                right: rect.left.max(rect.right),
+                bottom: rect.bottom.min(rect.top),
+                top: rect.bottom.max(rect.top),
+            });
+        }
+        if glyphs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut heights: Vec<f32> = glyphs.iter().map(|g| g.top - g.bottom).collect();
+        heights.sort_by(f32::total_cmp);
+        let height = heights[heights.len() / 2].max(1.0);
+
+        // Lines from top to bottom, each with its glyphs from left to right
+        glyphs.sort_by(|a, b| b.center_y().total_cmp(&a.center_y()));
+        let mut lines: Vec<(f32, Vec<Glyph>)> = Vec::new();
+        for glyph in glyphs {
+            match lines.last_mut() {
+                Some((y, line)) if *y - glyph.center_y() <= height / 2.0 => line.push(glyph),
+                _ => lines.push((glyph.center_y(), vec![glyph])),
+            }
+        }
+        let lines: Vec<Line> = lines
+            .into_iter()
+            .map(|(center_y, mut glyphs)| {
+                glyphs.sort_by(|a, b| a.left.total_cmp(&b.left));
+                Line {
+                    center_y,
+                    bottom: glyphs.iter().map(|g| g.bottom).fold(f32::MAX, f32::min),
+                    top: glyphs.iter().map(|g| g.top).fold(f32::MIN, f32::max),
+                    segments: segments(glyphs, height),
+                }
+            })
+            .collect();
+
+        let mut tables = Vec::new();
+        let mut start = 0;
+        while start < lines.len() {
+            let mut end = start;
+            while end < lines.len()
+                && lines[end].segments.len() >= 2
+                && (end == start || lines[end - 1].center_y - lines[end].center_y <= 3.0 * height)
+            {
+                end += 1;
+            }
+            if end - start >= 2 {
+                tables.extend(table(&lines[start..end]));
+                start = end;
+            } else {
+                start += 1;
+            }
+        }
+        Ok(tables)
+    }
+}
+
+/// Splits a line of glyphs, sorted from left to right, into segments.
+fn segments(line: Vec<Glyph>, height: f32) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+    for glyph in line {
+        match segments.last_mut() {
+            Some(segment) if glyph.left - segment.right <= 2.0 * height => {
+                if glyph.left - segment.right > 0.15 * height {
+                    segment.text.push(' ');
+                }
+                segment.text.push(glyph.ch);
+                segment.right = segment.right.max(glyph.right);
+            }
+            _ => segments.push(Segment {
+                left: glyph.left,
+                right: glyph.right,
+                text: glyph.ch.to_string(),
+            }),
+        }
+    }
+    segments
+}
+
+/// Builds a table from consecutive lines, or `None` if they do not form at least two columns.
+fn table(lines: &[Line]) -> Option<PdfiumTable> {
+    let mut ranges: Vec<(f32, f32)> = lines
+        .iter()
+        .flat_map(|line| line.segments.iter().map(|s| (s.left, s.right)))
+        .collect();
+    ranges.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut columns: Vec<(f32, f32)> = Vec::new();
+    for (left, right) in ranges {
+        match columns.last_mut() {
+            Some(column) if left <= column.1 => column.1 = column.1.max(right),
+            _ => columns.push((left, right)),
+        }
+    }
+    if columns.len() < 2 {
+        return None;
+    }
+
+    let mut cells = vec![vec![String::new(); columns.len()]; lines.len()];
+    let (mut top, mut bottom) = (f32::MIN, f32::MAX);
+    for (row, line) in lines.iter().enumerate() {
+        top = top.max(line.top);
+        bottom = bottom.min(line.bottom);
+        for segment in &line.segments {
+            let center = (segment.left + segment.right) / 2.0;
+            let column = columns
+                .iter()
+                .position(|(left, right)| (*left..=*right).contains(&center))?;
+            let cell = &mut cells[row][column];
+            if !cell.is_empty() {
+                cell.push(' ');
+            }
+            cell.push_str(&segment.text);
+        }
+    }
+    let (left, right) = (columns[0].0, columns[columns.len() - 1].1);
+    Some(PdfiumTable {
+        bounds: PdfiumRect::new(left, top, right, bottom),
+        cells,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_detect_tables() {
+        let document = PdfiumDocument::new_from_path("resources/table.pdf", None).unwrap();
+        let text = document.page(0).unwrap().text().unwrap();
+        let tables = text.detect_tables().unwrap();
+        assert_eq!(tables.len(), 1);
+
+        let table = &tables[0];
+        assert_eq!(table.row_count(), 4);
+        assert_eq!(table.column_count(), 3);
+        assert_eq!(table.cells[0], ["Name", "Qty", "Price"]);
+        assert_eq!(table.cell(1, 0), Some("Green apple"));
+        assert_eq!(table.cell(3, 2), Some("7.25"));
+        assert_eq!(table.cell(4, 0), None);
+    }
+}
@@ -0,0 +1,56 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::pdfium_types::FS_POINTF;
+
+/// Rust interface to FS_POINTF
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfiumPoint {
+    /// The x-coordinate of the point.
+    pub x: f32,
+    /// The y-coordinate of the point.
+    pub y: f32,
+}
+
+impl PdfiumPoint {
+    /// Creates a new [`PdfiumPoint`] with the given coordinates.
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a new [`PdfiumPoint`] at the origin (`0.0`, `0.0`).
+    pub const fn zero() -> Self {
+        Self { x: 0.0, y: 0.0 }
+    }
+}
+
+impl From<FS_POINTF> for PdfiumPoint {
+    fn from(point: FS_POINTF) -> Self {
+        Self::new(point.x, point.y)
+    }
+}
+
+impl From<&PdfiumPoint> for FS_POINTF {
+    fn from(point: &PdfiumPoint) -> Self {
+        FS_POINTF {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
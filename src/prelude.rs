@@ -0,0 +1,41 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Commonly used types and functions of PDFium-rs
+//!
+//! Import everything needed for loading, rendering and basic editing at once:
+//!
+//! ```rust
+//! use pdfium::prelude::*;
+//!
+//! fn first_page_size(path: &str) -> PdfiumResult<(f32, f32)> {
+//!     let document = PdfiumDocument::new_from_path(path, None)?;
+//!     let page: PdfiumPage = document.page(0)?;
+//!     let bounds: PdfiumRect = page.boundaries().default()?;
+//!     Ok((bounds.width(), bounds.height()))
+//! }
+//!
+//! let (width, height) = first_page_size("resources/groningen.pdf").unwrap();
+//! assert!(width > 0.0 && height > 0.0);
+//! ```
+
+pub use crate::{
+    lib, set_library_location, PdfiumBitmap, PdfiumColor, PdfiumDocument, PdfiumError,
+    PdfiumMatrix, PdfiumPage, PdfiumRect, PdfiumRenderConfig, PdfiumRenderFlags, PdfiumResult,
+};
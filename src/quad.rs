@@ -0,0 +1,205 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{pdfium_types::FS_QUADPOINTSF, PdfiumPoint, PdfiumRect};
+
+/// Rust interface to FS_QUADPOINTSF
+///
+/// A quadrilateral defined by four corner points. For a rotated or skewed rectangle the
+/// corner names refer to the corners before the rotation was applied.
+///
+/// The conversion from and to [`FS_QUADPOINTSF`] follows the order used for the
+/// `QuadPoints` of annotations: (x1, y1) is the top-left corner, (x2, y2) the top-right,
+/// (x3, y3) the bottom-left and (x4, y4) the bottom-right corner.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfiumQuad {
+    /// The top-left corner.
+    pub top_left: PdfiumPoint,
+    /// The top-right corner.
+    pub top_right: PdfiumPoint,
+    /// The bottom-left corner.
+    pub bottom_left: PdfiumPoint,
+    /// The bottom-right corner.
+    pub bottom_right: PdfiumPoint,
+}
+
+impl PdfiumQuad {
+    /// Creates a new [`PdfiumQuad`] with the given corners.
+    pub const fn new(
+        top_left: PdfiumPoint,
+        top_right: PdfiumPoint,
+        bottom_left: PdfiumPoint,
+        bottom_right: PdfiumPoint,
+    ) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+        }
+    }
+
+    /// Creates a new [`PdfiumQuad`] with the corners of the given [`PdfiumRect`].
+    pub fn new_from_rect(rect: &PdfiumRect) -> Self {
+        Self {
+            top_left: PdfiumPoint::new(rect.left, rect.top),
+            top_right: PdfiumPoint::new(rect.right, rect.top),
+            bottom_left: PdfiumPoint::new(rect.left, rect.bottom),
+            bottom_right: PdfiumPoint::new(rect.right, rect.bottom),
+        }
+    }
+
+    /// Returns the four corners of this [`PdfiumQuad`].
+    pub fn points(&self) -> [PdfiumPoint; 4] {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_left,
+            self.bottom_right,
+        ]
+    }
+
+    /// Returns the smallest axis-aligned [`PdfiumRect`] containing this [`PdfiumQuad`].
+    pub fn to_rect(&self) -> PdfiumRect {
+        let points = self.points();
+        let mut rect = PdfiumRect::new(points[0].x, points[0].y, points[0].x, points[0].y);
+        for point in &points[1..] {
+            rect.left = rect.left.min(point.x);
+            rect.right = rect.right.max(point.x);
+            rect.bottom = rect.bottom.min(point.y);
+            rect.top = rect.top.max(point.y);
+        }
+        rect
+    }
+
+    /// Returns `true` if the sides of this [`PdfiumQuad`] are parallel to the x and y axes.
+    ///
+    /// In that case [`PdfiumQuad::to_rect`] represents this quad without loss. This is true
+    /// for unrotated rectangles and rectangles rotated by a multiple of 90 degrees.
+    pub fn is_axis_aligned(&self) -> bool {
+        let (tl, tr, bl, br) = (
+            self.top_left,
+            self.top_right,
+            self.bottom_left,
+            self.bottom_right,
+        );
+        let upright = approx_eq(tl.y, tr.y)
+            && approx_eq(bl.y, br.y)
+            && approx_eq(tl.x, bl.x)
+            && approx_eq(tr.x, br.x);
+        let sideways = approx_eq(tl.x, tr.x)
+            && approx_eq(bl.x, br.x)
+            && approx_eq(tl.y, bl.y)
+            && approx_eq(tr.y, br.y);
+        upright || sideways
+    }
+}
+
+/// Compares two coordinates, allowing for rounding errors introduced by transformations.
+fn approx_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() <= 1e-4 * a.abs().max(b.abs()).max(1.0)
+}
+
+impl From<FS_QUADPOINTSF> for PdfiumQuad {
+    fn from(quad: FS_QUADPOINTSF) -> Self {
+        Self {
+            top_left: PdfiumPoint::new(quad.x1, quad.y1),
+            top_right: PdfiumPoint::new(quad.x2, quad.y2),
+            bottom_left: PdfiumPoint::new(quad.x3, quad.y3),
+            bottom_right: PdfiumPoint::new(quad.x4, quad.y4),
+        }
+    }
+}
+
+impl From<&PdfiumQuad> for FS_QUADPOINTSF {
+    fn from(quad: &PdfiumQuad) -> Self {
+        FS_QUADPOINTSF {
+            x1: quad.top_left.x,
+            y1: quad.top_left.y,
+            x2: quad.top_right.x,
+            y2: quad.top_right.y,
+            x3: quad.bottom_left.x,
+            y3: quad.bottom_left.y,
+            x4: quad.bottom_right.x,
+            y4: quad.bottom_right.y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{pdfium_types::FS_QUADPOINTSF, PdfiumPoint, PdfiumQuad, PdfiumRect};
+
+    #[test]
+    fn test_quad_conversion() {
+        let raw = FS_QUADPOINTSF {
+            x1: 10.0,
+            y1: 20.0,
+            x2: 30.0,
+            y2: 20.0,
+            x3: 10.0,
+            y3: 5.0,
+            x4: 30.0,
+            y4: 5.0,
+        };
+        let quad = PdfiumQuad::from(raw);
+        assert_eq!(quad.top_left, PdfiumPoint::new(10.0, 20.0));
+        assert_eq!(quad.top_right, PdfiumPoint::new(30.0, 20.0));
+        assert_eq!(quad.bottom_left, PdfiumPoint::new(10.0, 5.0));
+        assert_eq!(quad.bottom_right, PdfiumPoint::new(30.0, 5.0));
+
+        let back: FS_QUADPOINTSF = (&quad).into();
+        assert_eq!(PdfiumQuad::from(back), quad);
+    }
+
+    #[test]
+    fn test_quad_to_rect() {
+        let rect = PdfiumRect::new(10.0, 20.0, 30.0, 5.0);
+        let quad = PdfiumQuad::new_from_rect(&rect);
+        assert!(quad.is_axis_aligned());
+        let bounds = quad.to_rect();
+        assert_eq!(bounds.left, 10.0);
+        assert_eq!(bounds.top, 20.0);
+        assert_eq!(bounds.right, 30.0);
+        assert_eq!(bounds.bottom, 5.0);
+
+        // A square rotated by 45 degrees
+        let quad = PdfiumQuad::new(
+            PdfiumPoint::new(0.0, 1.0),
+            PdfiumPoint::new(1.0, 2.0),
+            PdfiumPoint::new(1.0, 0.0),
+            PdfiumPoint::new(2.0, 1.0),
+        );
+        assert!(!quad.is_axis_aligned());
+        let bounds = quad.to_rect();
+        assert_eq!(bounds.left, 0.0);
+        assert_eq!(bounds.top, 2.0);
+        assert_eq!(bounds.right, 2.0);
+        assert_eq!(bounds.bottom, 0.0);
+
+        // A rectangle rotated by 90 degrees
+        let quad = PdfiumQuad::new(
+            PdfiumPoint::new(0.0, 0.0),
+            PdfiumPoint::new(0.0, 10.0),
+            PdfiumPoint::new(5.0, 0.0),
+            PdfiumPoint::new(5.0, 10.0),
+        );
+        assert!(quad.is_axis_aligned());
+    }
+}
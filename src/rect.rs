@@ -74,6 +74,26 @@ impl PdfiumRect {
     pub fn height(&self) -> f32 {
         self.top - self.bottom
     }
+
+    /// Returns `true` if the point (`x`, `y`) lies inside or on the edge of this [`PdfiumRect`].
+    ///
+    /// Works regardless of whether `top` is above or below `bottom`.
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        let (x_min, x_max) = (self.left.min(self.right), self.left.max(self.right));
+        let (y_min, y_max) = (self.bottom.min(self.top), self.bottom.max(self.top));
+        (x_min..=x_max).contains(&x) && (y_min..=y_max).contains(&y)
+    }
+
+    /// Returns `true` if this [`PdfiumRect`] and `other` overlap or touch.
+    ///
+    /// Works regardless of whether `top` is above or below `bottom`.
+    pub fn intersects(&self, other: &PdfiumRect) -> bool {
+        let (x_min, x_max) = (self.left.min(self.right), self.left.max(self.right));
+        let (y_min, y_max) = (self.bottom.min(self.top), self.bottom.max(self.top));
+        let (other_x_min, other_x_max) = (other.left.min(other.right), other.left.max(other.right));
+        let (other_y_min, other_y_max) = (other.bottom.min(other.top), other.bottom.max(other.top));
+        x_min <= other_x_max && other_x_min <= x_max && y_min <= other_y_max && other_y_min <= y_max
+    }
 }
 
 impl From<FS_RECTF> for PdfiumRect {
@@ -92,3 +112,30 @@ impl From<&PdfiumRect> for FS_RECTF {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_contains() {
+        let rect = PdfiumRect::new_from_lbrt((10.0, 20.0, 110.0, 220.0));
+        assert!(rect.contains(10.0, 20.0));
+        assert!(rect.contains(60.0, 120.0));
+        assert!(!rect.contains(5.0, 120.0));
+        assert!(!rect.contains(60.0, 221.0));
+
+        // Flipped y-axis (e.g. device coordinates)
+        let rect = PdfiumRect::new(10.0, 20.0, 110.0, 220.0);
+        assert!(rect.contains(60.0, 120.0));
+    }
+
+    #[test]
+    fn test_rect_intersects() {
+        let rect = PdfiumRect::new_from_lbrt((0.0, 0.0, 100.0, 100.0));
+        assert!(rect.intersects(&PdfiumRect::new_from_lbrt((50.0, 50.0, 150.0, 150.0))));
+        assert!(rect.intersects(&PdfiumRect::new_from_lbrt((100.0, 0.0, 200.0, 100.0))));
+        assert!(!rect.intersects(&PdfiumRect::new_from_lbrt((101.0, 0.0, 200.0, 100.0))));
+        assert!(!rect.intersects(&PdfiumRect::new_from_lbrt((0.0, -50.0, 100.0, -1.0))));
+    }
+}
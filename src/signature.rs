@@ -19,13 +19,16 @@
 
 use crate::{
     error::{PdfiumError, PdfiumResult},
+    lib,
     pdfium_types::{Handle, SignatureHandle, FPDF_SIGNATURE},
+    PdfiumDocument,
 };
 
 /// # Rust interface to FPDF_SIGNATURE
 #[derive(Debug, Clone)]
 pub struct PdfiumSignature {
     handle: SignatureHandle,
+    owner: Option<PdfiumDocument>,
 }
 
 impl PdfiumSignature {
@@ -35,9 +38,36 @@ impl PdfiumSignature {
         } else {
             Ok(Self {
                 handle: Handle::new_const(handle), // TODO: check close is not needed
+                owner: None,
             })
         }
     }
+
+    pub(crate) fn set_owner(&mut self, owner: PdfiumDocument) {
+        self.owner = Some(owner);
+    }
+
+    /// Returns the byte range of this [`PdfiumSignature`].
+    ///
+    /// The byte range consists of pairs of offset and length, describing the parts of the
+    /// file covered by the signature.
+    pub fn byte_range(&self) -> Vec<i32> {
+        let len = lib().FPDFSignatureObj_GetByteRange(self, &mut 0, 0);
+        let mut buffer = vec![0i32; len as usize];
+        if len > 0 {
+            lib().FPDFSignatureObj_GetByteRange(self, &mut buffer[0], len);
+        }
+        buffer
+    }
+
+    /// Returns the contents of this [`PdfiumSignature`], the DER-encoded PKCS#1 binary or
+    /// PKCS#7 details, depending on the sub-filter.
+    pub fn contents(&self) -> Vec<u8> {
+        let len = lib().FPDFSignatureObj_GetContents(self, None, 0);
+        let mut buffer = vec![0u8; len as usize];
+        lib().FPDFSignatureObj_GetContents(self, Some(&mut buffer), len);
+        buffer
+    }
 }
 
 impl From<&PdfiumSignature> for FPDF_SIGNATURE {
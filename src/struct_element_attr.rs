@@ -17,9 +17,13 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::{ffi::CString, os::raw::c_ulong};
+
 use crate::{
     error::{PdfiumError, PdfiumResult},
+    lib,
     pdfium_types::{Handle, StructElementAttrHandle, FPDF_STRUCTELEMENT_ATTR},
+    PdfiumStructAttrValue,
 };
 
 /// # Rust interface to FPDF_STRUCTELEMENT_ATTR
@@ -38,6 +42,36 @@ impl PdfiumStructElementAttr {
             })
         }
     }
+
+    /// Returns the number of attributes in this [`PdfiumStructElementAttr`] map, or -1 on error.
+    pub fn count(&self) -> i32 {
+        lib().FPDF_StructElement_Attr_GetCount(self)
+    }
+
+    /// Returns the name of the attribute at `index` in this [`PdfiumStructElementAttr`] map.
+    pub fn name(&self, index: i32) -> PdfiumResult<String> {
+        let lib = lib();
+        let mut buflen: c_ulong = 0;
+        lib.FPDF_StructElement_Attr_GetName(self, index, None, 0, &mut buflen)?;
+        let mut buffer = vec![0u8; buflen as usize];
+        lib.FPDF_StructElement_Attr_GetName(self, index, Some(&mut buffer), buflen, &mut buflen)?;
+        let len = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+        Ok(String::from_utf8_lossy(&buffer[..len]).into_owned())
+    }
+
+    /// Returns the typed value of the attribute `name` in this [`PdfiumStructElementAttr`] map.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if let PdfiumStructAttrValue::Number(row_span) = attr.value("RowSpan")? {
+    ///     println!("cell spans {row_span} rows");
+    /// }
+    /// ```
+    pub fn value(&self, name: &str) -> PdfiumResult<PdfiumStructAttrValue> {
+        let name = CString::new(name)?;
+        lib().FPDF_StructElement_Attr_GetValue(self, &name)?.value()
+    }
 }
 
 impl From<&PdfiumStructElementAttr> for FPDF_STRUCTELEMENT_ATTR {
@@ -45,3 +79,40 @@ impl From<&PdfiumStructElementAttr> for FPDF_STRUCTELEMENT_ATTR {
         struct_element_attr.handle.handle()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_struct_attr_value() {
+        let document = PdfiumDocument::new_from_path("resources/tagged_table.pdf", None).unwrap();
+        let page = document.page(0).unwrap();
+        let lib = lib();
+        let tree = lib.FPDF_StructTree_GetForPage(&page).unwrap();
+        let table = lib.FPDF_StructTree_GetChildAtIndex(&tree, 0).unwrap();
+        let row = lib.FPDF_StructElement_GetChildAtIndex(&table, 0).unwrap();
+        let cell = lib.FPDF_StructElement_GetChildAtIndex(&row, 0).unwrap();
+        assert_eq!(lib.FPDF_StructElement_GetAttributeCount(&cell), 1);
+
+        let attr = lib
+            .FPDF_StructElement_GetAttributeAtIndex(&cell, 0)
+            .unwrap();
+        let names: Vec<_> = (0..attr.count()).map(|i| attr.name(i).unwrap()).collect();
+        assert!(names.contains(&"RowSpan".to_string()));
+
+        assert_eq!(
+            attr.value("RowSpan").unwrap(),
+            PdfiumStructAttrValue::Number(2.0)
+        );
+        assert_eq!(
+            attr.value("Scope").unwrap(),
+            PdfiumStructAttrValue::Name("Column".to_string())
+        );
+        assert_eq!(
+            attr.value("Summary").unwrap(),
+            PdfiumStructAttrValue::String("Totals".to_string())
+        );
+        assert!(attr.value("ColSpan").is_err());
+    }
+}
@@ -17,11 +17,38 @@
 // STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
 // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::os::raw::c_ulong;
+
 use crate::{
+    c_api::utf16::utf16le_to_string,
     error::{PdfiumError, PdfiumResult},
-    pdfium_types::{Handle, StructElementAttrValueHandle, FPDF_STRUCTELEMENT_ATTR_VALUE},
+    lib,
+    pdfium_constants::{
+        FPDF_OBJECT_ARRAY, FPDF_OBJECT_BOOLEAN, FPDF_OBJECT_NAME, FPDF_OBJECT_NUMBER,
+        FPDF_OBJECT_STRING,
+    },
+    pdfium_types::{
+        Handle, StructElementAttrValueHandle, FPDF_BOOL, FPDF_STRUCTELEMENT_ATTR_VALUE,
+    },
 };
 
+/// Typed value of a structure element attribute
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfiumStructAttrValue {
+    /// A boolean value
+    Boolean(bool),
+    /// A number value (e.g. `RowSpan` or `ColSpan` of a table cell)
+    Number(f32),
+    /// A string value
+    String(String),
+    /// A name value (e.g. `Scope` of a table header cell)
+    Name(String),
+    /// An array of values
+    Array(Vec<PdfiumStructAttrValue>),
+    /// A value of a type that is not (yet) supported, e.g. a dictionary
+    Unsupported,
+}
+
 /// # Rust interface to FPDF_STRUCTELEMENT_ATTR_VALUE
 #[derive(Debug, Clone)]
 pub struct PdfiumStructElementAttrValue {
@@ -38,6 +65,53 @@ impl PdfiumStructElementAttrValue {
             })
         }
     }
+
+    /// Returns the type of this [`PdfiumStructElementAttrValue`] as one of the
+    /// `FPDF_OBJECT_*` constants, or `FPDF_OBJECT_UNKNOWN` in case of failure.
+    pub fn value_type(&self) -> i32 {
+        lib().FPDF_StructElement_Attr_GetType(self)
+    }
+
+    /// Reads this [`PdfiumStructElementAttrValue`] as the Rust type matching its
+    /// [`PdfiumStructElementAttrValue::value_type`].
+    pub fn value(&self) -> PdfiumResult<PdfiumStructAttrValue> {
+        Ok(match self.value_type() {
+            FPDF_OBJECT_BOOLEAN => {
+                let mut value: FPDF_BOOL = 0;
+                lib().FPDF_StructElement_Attr_GetBooleanValue(self, &mut value)?;
+                PdfiumStructAttrValue::Boolean(value != 0)
+            }
+            FPDF_OBJECT_NUMBER => {
+                let mut value = 0.0;
+                lib().FPDF_StructElement_Attr_GetNumberValue(self, &mut value)?;
+                PdfiumStructAttrValue::Number(value)
+            }
+            FPDF_OBJECT_STRING => PdfiumStructAttrValue::String(self.string_value()?),
+            FPDF_OBJECT_NAME => PdfiumStructAttrValue::Name(self.string_value()?),
+            FPDF_OBJECT_ARRAY => {
+                let lib = lib();
+                let count = lib.FPDF_StructElement_Attr_CountChildren(self);
+                let values = (0..count)
+                    .map(|index| {
+                        lib.FPDF_StructElement_Attr_GetChildAtIndex(self, index)?
+                            .value()
+                    })
+                    .collect::<PdfiumResult<_>>()?;
+                PdfiumStructAttrValue::Array(values)
+            }
+            _ => PdfiumStructAttrValue::Unsupported,
+        })
+    }
+
+    /// Reads a string or name value, which PDFium returns as UTF-16LE.
+    fn string_value(&self) -> PdfiumResult<String> {
+        let lib = lib();
+        let mut buflen: c_ulong = 0;
+        lib.FPDF_StructElement_Attr_GetStringValue(self, None, 0, &mut buflen)?;
+        let mut buffer = vec![0u8; buflen as usize];
+        lib.FPDF_StructElement_Attr_GetStringValue(self, Some(&mut buffer), buflen, &mut buflen)?;
+        Ok(utf16le_to_string(&buffer))
+    }
 }
 
 impl From<&PdfiumStructElementAttrValue> for FPDF_STRUCTELEMENT_ATTR_VALUE {
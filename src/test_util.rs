@@ -0,0 +1,41 @@
+// PDFium-rs -- Modern Rust interface to PDFium, the PDF library from Google
+//
+// Copyright (c) 2025 Martin van der Werff <github (at) newinnovations.nl>
+//
+// This file is part of PDFium-rs.
+//
+// PDFium-rs is free software: you can redistribute it and/or modify it under the terms of
+// the GNU General Public License as published by the Free Software Foundation, either version 3
+// of the License, or (at your option) any later version.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR
+// IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND
+// FITNESS FOR A PARTICULAR PURPOSE ARE DISCLAIMED. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR ANY
+// DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT
+// LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR
+// BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT,
+// STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+// OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{lib, pdfium_constants::FPDF_FILLMODE_ALTERNATE, PdfiumPageObject};
+
+/// Creates a rectangle path object filled with an opaque RGB color, ready to be inserted
+/// into a page.
+pub(crate) fn filled_rect(
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    r: u32,
+    g: u32,
+    b: u32,
+) -> PdfiumPageObject {
+    let rect = lib()
+        .FPDFPageObj_CreateNewRect(x, y, width, height)
+        .unwrap();
+    lib().FPDFPageObj_SetFillColor(&rect, r, g, b, 255).unwrap();
+    lib()
+        .FPDFPath_SetDrawMode(&rect, FPDF_FILLMODE_ALTERNATE, 0)
+        .unwrap();
+    rect
+}